@@ -1,16 +1,17 @@
 use std::{
-    ffi::{CStr, CString, NulError},
-    path::Path,
+    ffi::{c_uint, CStr, CString, NulError},
+    path::{Path, PathBuf},
     ptr,
 };
 
-use gdal_sys::{CPLErr, GDALDatasetH, GDALMajorObjectH};
+use gdal_sys::{CPLErr, GDALAccess, GDALDatasetH, GDALMajorObjectH};
 
 use crate::cpl::CslStringList;
 use crate::errors::*;
-use crate::options::DatasetOptions;
+use crate::options::{DatasetOptions, GdalOpenFlags};
+use crate::programs::ProgressCallback;
 use crate::raster::RasterCreationOptions;
-use crate::utils::{_last_cpl_err, _last_null_pointer_err, _path_to_c_string, _string};
+use crate::utils::{_last_cpl_err, _last_err_or_null_pointer, _path_to_c_string, _string};
 use crate::{
     gdal_major_object::MajorObject, spatial_ref::SpatialRef, Driver, GeoTransform, Metadata,
 };
@@ -47,12 +48,38 @@ impl DatasetCapability {
 /// [vector-data-model]: https://gdal.org/user/vector_data_model.html
 /// [raster-data-model]: https://gdal.org/user/raster_data_model.html
 /// [GDALDataset]: https://gdal.org/api/gdaldataset_cpp.html#_CPPv411GDALDataset
+///
+/// # Threads and async runtimes
+///
+/// A `Dataset` is [`Send`] but not [`Sync`]: it can be moved to another thread,
+/// but only one thread may use it at a time.
+///
+/// Opening a dataset, especially a remote one through `/vsicurl/` or `/vsis3/`,
+/// blocks on IO. Inside an async runtime, open it on a thread meant for
+/// blocking work and hand it back to the async task.
+/// [`Dataset::open_blocking_task`] builds the closure to run there, e.g. with tokio:
+///
+/// ```rust, ignore
+/// let ds = tokio::task::spawn_blocking(gdal::Dataset::open_blocking_task(
+///     "/vsicurl/https://example.com/cog.tif",
+/// ))
+/// .await??;
+/// ```
+///
+/// Reads from the returned dataset block as well, so long operations should be
+/// moved to such a thread too, taking the dataset with them.
 #[derive(Debug)]
 pub struct Dataset {
     c_dataset: GDALDatasetH,
     closed: bool,
+    /// The flags and open options the dataset was opened with, used to reopen it.
+    c_open_flags: c_uint,
+    open_options: Vec<String>,
 }
 
+/// `GDAL_OF_SHARED`, left out of [`GdalOpenFlags`] because it subverts [`Send`].
+const GDAL_OF_SHARED: c_uint = 0x20;
+
 // GDAL Docs state: The returned dataset should only be accessed by one thread at a time.
 // See: https://gdal.org/api/raster_c_api.html#_CPPv48GDALOpenPKc10GDALAccess
 unsafe impl Send for Dataset {}
@@ -76,6 +103,8 @@ impl Dataset {
         Dataset {
             c_dataset,
             closed: false,
+            c_open_flags: 0,
+            open_options: Vec::new(),
         }
     }
 
@@ -93,11 +122,61 @@ impl Dataset {
         Self::_open_ex(path.as_ref(), options)
     }
 
+    /// Returns a closure that opens the dataset at `path` with default options, to run on a
+    /// thread where blocking is allowed.
+    ///
+    /// The closure is `Send + 'static`, so it can be passed to e.g.
+    /// `tokio::task::spawn_blocking` or [`std::thread::spawn`], and the opened dataset moved
+    /// back to the caller. See the [`Dataset`] docs for the thread-safety rules that apply to
+    /// it afterward.
+    ///
+    /// ```
+    /// # use gdal::Dataset;
+    /// let open = Dataset::open_blocking_task("fixtures/tinymarble.tif");
+    /// let ds = std::thread::spawn(open).join().unwrap().unwrap();
+    /// assert_eq!(ds.raster_size(), (100, 50));
+    /// ```
+    pub fn open_blocking_task<P: Into<PathBuf>>(
+        path: P,
+    ) -> impl FnOnce() -> Result<Dataset> + Send + 'static {
+        let path = path.into();
+        move || Dataset::open(path)
+    }
+
+    /// Open a dataset with `GDAL_OF_SHARED` added to the `options` flags.
+    ///
+    /// GDAL keeps a list of the datasets opened in shared mode. Opening the same file in shared
+    /// mode again, from the same thread and with the same access mode, returns the dataset from
+    /// that list instead of opening a new one, so it reuses the blocks that dataset already
+    /// has cached. Each handle holds a reference, and the dataset is closed with the last one.
+    ///
+    /// Use [`Dataset::open_ex`] for independent datasets that can be used from several threads.
+    ///
+    /// See [`GDALOpenEx`].
+    ///
+    /// # Safety
+    /// Shared handles to the same dataset alias each other, even though [`Dataset`] is [`Send`].
+    /// None of them may be used while another one is used from a different thread: in practice,
+    /// keep all shared handles to a file on the thread that opened them.
+    ///
+    /// [`GDALOpenEx`]: https://gdal.org/doxygen/gdal_8h.html#a9cb8585d0b3c16726b08e25bcc94274a
+    pub unsafe fn open_shared<P: AsRef<Path>>(path: P, options: DatasetOptions) -> Result<Dataset> {
+        let c_open_flags = options.open_flags.bits() | GDAL_OF_SHARED;
+        Self::_open_ex_with_flags(path.as_ref(), c_open_flags, options)
+    }
+
     fn _open_ex(path: &Path, options: DatasetOptions) -> Result<Dataset> {
+        Self::_open_ex_with_flags(path, options.open_flags.bits(), options)
+    }
+
+    fn _open_ex_with_flags(
+        path: &Path,
+        c_open_flags: c_uint,
+        options: DatasetOptions,
+    ) -> Result<Dataset> {
         crate::driver::_register_drivers();
 
         let c_filename = _path_to_c_string(path)?;
-        let c_open_flags = options.open_flags.bits();
 
         // handle driver params:
         // we need to keep the CStrings and the pointers around
@@ -169,6 +248,7 @@ impl Dataset {
         };
 
         let c_dataset = unsafe {
+            gdal_sys::CPLErrorReset();
             gdal_sys::GDALOpenEx(
                 c_filename.as_ptr(),
                 c_open_flags,
@@ -178,19 +258,76 @@ impl Dataset {
             )
         };
         if c_dataset.is_null() {
-            return Err(_last_null_pointer_err("GDALOpenEx"));
+            return Err(_last_err_or_null_pointer("GDALOpenEx"));
         }
         Ok(Dataset {
             c_dataset,
             closed: false,
+            c_open_flags,
+            open_options: options
+                .open_options
+                .unwrap_or_default()
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
         })
     }
 
+    /// Open a second, independent handle to the file backing this dataset, in read-only mode.
+    ///
+    /// The file is reopened with the same driver, open flags and open options as `self`,
+    /// except for `GDAL_OF_UPDATE`.
+    ///
+    /// The new handle can be moved to another thread and used concurrently with `self`,
+    /// e.g. one handle per worker of a tile server. Writes made through `self` are only
+    /// visible to it once flushed, see [`flush_cache`][Self::flush_cache].
+    /// Datasets that are not backed by a file, such as `MEM` datasets, can't be reopened
+    /// and return an error.
+    ///
+    /// See [`try_clone_shared`][Self::try_clone_shared] to share cached blocks between handles
+    /// used by a single thread.
+    pub fn try_clone(&self) -> Result<Dataset> {
+        let c_open_flags = self.c_open_flags & !GdalOpenFlags::GDAL_OF_UPDATE.bits();
+        self._reopen(c_open_flags & !GDAL_OF_SHARED)
+    }
+
+    /// Reopen the file backing this dataset in shared mode, see [`Dataset::open_shared`].
+    ///
+    /// The file is reopened with the same driver, access mode, open flags and open options as
+    /// `self`, plus `GDAL_OF_SHARED`. If `self` was itself opened with
+    /// [`open_shared`][Self::open_shared], the returned handle refers to the same dataset, and
+    /// shares its cached blocks.
+    ///
+    /// # Safety
+    /// See [`Dataset::open_shared`]: the returned handle may alias `self`, so neither may be
+    /// used while the other is used from a different thread.
+    pub unsafe fn try_clone_shared(&self) -> Result<Dataset> {
+        let mut c_open_flags = self.c_open_flags | GDAL_OF_SHARED;
+        if unsafe { gdal_sys::GDALGetAccess(self.c_dataset) } == GDALAccess::GA_Update as i32 {
+            c_open_flags |= GdalOpenFlags::GDAL_OF_UPDATE.bits();
+        }
+        self._reopen(c_open_flags)
+    }
+
+    fn _reopen(&self, c_open_flags: c_uint) -> Result<Dataset> {
+        let path = self.description()?;
+        let driver = self.driver().short_name();
+        let allowed_drivers = [driver.as_str()];
+        let open_options: Vec<&str> = self.open_options.iter().map(String::as_str).collect();
+        let options = DatasetOptions {
+            allowed_drivers: Some(&allowed_drivers),
+            open_options: Some(&open_options),
+            ..Default::default()
+        };
+        Self::_open_ex_with_flags(Path::new(&path), c_open_flags, options)
+    }
+
     /// Flush all write cached data to disk.
     ///
     /// See [`gdal_sys::GDALFlushCache`].
     ///
-    /// Note: on GDAL versions older than 3.7, this function always succeeds.
+    /// Note: on GDAL versions older than 3.7, `GDALFlushCache` doesn't report failures,
+    /// so this function checks the last error raised while flushing instead.
     pub fn flush_cache(&mut self) -> Result<()> {
         #[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
         {
@@ -202,14 +339,23 @@ impl Dataset {
         #[cfg(not(any(all(major_is_3, minor_ge_7), major_ge_4)))]
         {
             unsafe {
+                gdal_sys::CPLErrorReset();
                 gdal_sys::GDALFlushCache(self.c_dataset);
             }
+            let cpl_err = unsafe { gdal_sys::CPLGetLastErrorType() };
+            if cpl_err == CPLErr::CE_Failure || cpl_err == CPLErr::CE_Fatal {
+                return Err(_last_cpl_err(cpl_err));
+            }
         }
         Ok(())
     }
 
     /// Close the dataset.
     ///
+    /// Unlike dropping the dataset, this reports errors that happen while writing
+    /// pending changes to disk, such as running out of space. The dataset is consumed,
+    /// so it won't be closed again on drop.
+    ///
     /// See [`gdal_sys::GDALClose`].
     ///
     /// Note: on GDAL versions older than 3.7.0, this function always succeeds.
@@ -275,31 +421,56 @@ impl Dataset {
         filename: P,
         options: &RasterCreationOptions,
     ) -> Result<Dataset> {
-        fn _create_copy(
-            ds: &Dataset,
-            driver: &Driver,
-            filename: &Path,
-            options: &CslStringList,
-        ) -> Result<Dataset> {
-            let c_filename = _path_to_c_string(filename)?;
-
-            let c_dataset = unsafe {
-                gdal_sys::GDALCreateCopy(
-                    driver.c_driver(),
-                    c_filename.as_ptr(),
-                    ds.c_dataset,
-                    0,
-                    options.as_ptr(),
-                    None,
-                    ptr::null_mut(),
-                )
-            };
-            if c_dataset.is_null() {
-                return Err(_last_null_pointer_err("GDALCreateCopy"));
-            }
-            Ok(unsafe { Dataset::from_c_dataset(c_dataset) })
+        self._create_copy(driver, filename.as_ref(), options, None)
+    }
+
+    /// Like [`create_copy`](Self::create_copy), reporting the progress of the copy to `progress`.
+    ///
+    /// The callback receives the completed fraction, between `0.0` and `1.0`, and a possibly
+    /// empty status message. Returning `false` cancels the copy, in which case an error is
+    /// returned.
+    ///
+    /// See: [`GDALCreateCopy`](https://gdal.org/api/raster_c_api.html#_CPPv414GDALCreateCopy11GDALDriverHPKc12GDALDatasetHi12CSLConstList16GDALProgressFuncPv)
+    pub fn create_copy_with_progress<P: AsRef<Path>, F: FnMut(f64, &str) -> bool + 'static>(
+        &self,
+        driver: &Driver,
+        filename: P,
+        options: &RasterCreationOptions,
+        progress: F,
+    ) -> Result<Dataset> {
+        let mut progress = ProgressCallback::new(progress);
+        self._create_copy(driver, filename.as_ref(), options, Some(&mut progress))
+    }
+
+    fn _create_copy(
+        &self,
+        driver: &Driver,
+        filename: &Path,
+        options: &CslStringList,
+        progress: Option<&mut ProgressCallback>,
+    ) -> Result<Dataset> {
+        let c_filename = _path_to_c_string(filename)?;
+        let (pfn_progress, progress_data) = match progress {
+            Some(progress) => (progress.func(), progress.data()),
+            None => (None, ptr::null_mut()),
+        };
+
+        let c_dataset = unsafe {
+            gdal_sys::CPLErrorReset();
+            gdal_sys::GDALCreateCopy(
+                driver.c_driver(),
+                c_filename.as_ptr(),
+                self.c_dataset,
+                0,
+                options.as_ptr(),
+                pfn_progress,
+                progress_data,
+            )
+        };
+        if c_dataset.is_null() {
+            return Err(_last_err_or_null_pointer("GDALCreateCopy"));
         }
-        _create_copy(self, driver, filename.as_ref(), options)
+        Ok(unsafe { Dataset::from_c_dataset(c_dataset) })
     }
 
     /// Fetch the driver to which this dataset relates.
@@ -383,8 +554,11 @@ mod tests {
     use gdal_sys::GDALAccess;
 
     use crate::dataset::DatasetCapability;
-    use crate::test_utils::{fixture, open_gpkg_for_update};
-    use crate::GdalOpenFlags;
+    use crate::raster::Buffer;
+    use crate::spatial_ref::SpatialRef;
+    use crate::test_utils::{fixture, open_gpkg_for_update, InMemoryFixture, SuppressGDALErrorLog};
+    use crate::vector::{LayerAccess, LayerOptions, OGRwkbGeometryType};
+    use crate::{DriverManager, GdalOpenFlags};
 
     use super::*;
 
@@ -394,6 +568,174 @@ mod tests {
         dataset.close().unwrap();
     }
 
+    #[test]
+    fn test_open_on_another_thread() {
+        let ds = std::thread::spawn(|| Dataset::open(fixture("tinymarble.tif")))
+            .join()
+            .unwrap()
+            .unwrap();
+        assert_eq!(ds.raster_size(), (100, 50));
+    }
+
+    #[test]
+    fn test_open_failure_error_number() {
+        let _nolog = SuppressGDALErrorLog::new();
+        let options = DatasetOptions {
+            open_flags: GdalOpenFlags::GDAL_OF_VERBOSE_ERROR,
+            ..Default::default()
+        };
+        let err = Dataset::open_ex(fixture("does_not_exist.tif"), options).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                GdalError::CplError {
+                    class: CPLErr::CE_Failure,
+                    number: error_num::OPEN_FAILED,
+                    ..
+                }
+            ),
+            "{err:?}"
+        );
+        assert!(Dataset::open(fixture("does_not_exist.tif")).is_err());
+    }
+
+    #[test]
+    fn test_flush_cache_after_write() {
+        let output = InMemoryFixture::new("flush.tif");
+        let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+        let mut dataset = driver
+            .create_with_band_type::<u8, _>(output.path(), 8, 8, 1)
+            .unwrap();
+        let mut buffer = Buffer::new((8, 8), vec![3u8; 64]);
+        dataset
+            .rasterband(1)
+            .unwrap()
+            .write((0, 0), (8, 8), &mut buffer)
+            .unwrap();
+        dataset.flush_cache().unwrap();
+
+        // The flushed pixels are visible to a second handle while the first is still open.
+        let reader = Dataset::open(output.path()).unwrap();
+        let data = reader
+            .rasterband(1)
+            .unwrap()
+            .read_as::<u8>((0, 0), (8, 8), (8, 8), None)
+            .unwrap();
+        assert!(data.data().iter().all(|&v| v == 3));
+    }
+
+    #[test]
+    fn test_try_clone() {
+        let output = InMemoryFixture::new("try_clone.tif");
+        let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+        let mut dataset = driver
+            .create_with_band_type::<u8, _>(output.path(), 8, 8, 1)
+            .unwrap();
+        let mut buffer = Buffer::new((8, 8), (0..64).collect());
+        dataset
+            .rasterband(1)
+            .unwrap()
+            .write((0, 0), (8, 8), &mut buffer)
+            .unwrap();
+        dataset.flush_cache().unwrap();
+
+        let read = |ds: &Dataset| {
+            ds.rasterband(1)
+                .unwrap()
+                .read_as::<u8>((0, 0), (8, 8), (8, 8), None)
+                .unwrap()
+                .into_shape_and_vec()
+                .1
+        };
+        let clone = dataset.try_clone().unwrap();
+        let from_thread = std::thread::spawn(move || read(&clone)).join().unwrap();
+        assert_eq!(from_thread, read(&dataset));
+        assert_eq!(from_thread, buffer.data());
+
+        let _nolog = SuppressGDALErrorLog::new();
+        let mem = DriverManager::get_driver_by_name("MEM")
+            .unwrap()
+            .create("", 1, 1, 1)
+            .unwrap();
+        assert!(mem.try_clone().is_err());
+    }
+
+    #[test]
+    fn test_try_clone_shared() {
+        let output = InMemoryFixture::new("try_clone_shared.tif");
+        {
+            let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+            let dataset = driver
+                .create_with_band_type::<u8, _>(output.path(), 8, 8, 1)
+                .unwrap();
+            let mut buffer = Buffer::new((8, 8), (0..64).collect());
+            dataset
+                .rasterband(1)
+                .unwrap()
+                .write((0, 0), (8, 8), &mut buffer)
+                .unwrap();
+        }
+
+        let read = |ds: &Dataset| {
+            ds.rasterband(1)
+                .unwrap()
+                .read_as::<u8>((0, 0), (8, 8), (8, 8), None)
+                .unwrap()
+                .into_shape_and_vec()
+                .1
+        };
+        let first = unsafe { Dataset::open_shared(output.path(), Default::default()) }.unwrap();
+        let second = unsafe { first.try_clone_shared() }.unwrap();
+        assert_eq!(first.c_dataset(), second.c_dataset());
+        assert_eq!(read(&first), read(&second));
+        drop(first);
+        assert_eq!(read(&second), (0..64).collect::<Vec<u8>>());
+
+        // independent clones keep the driver and open options, but not update mode
+        let options = DatasetOptions {
+            open_flags: GdalOpenFlags::GDAL_OF_UPDATE | GdalOpenFlags::GDAL_OF_RASTER,
+            open_options: Some(&["NUM_THREADS=1"]),
+            ..Default::default()
+        };
+        let update = Dataset::open_ex(output.path(), options).unwrap();
+        let clone = update.try_clone().unwrap();
+        assert_ne!(update.c_dataset(), clone.c_dataset());
+        assert_eq!(
+            unsafe { gdal_sys::GDALGetAccess(clone.c_dataset()) },
+            GDALAccess::GA_ReadOnly as i32
+        );
+        assert_eq!(clone.open_options, ["NUM_THREADS=1"]);
+        let shared = unsafe { update.try_clone_shared() }.unwrap();
+        assert_eq!(
+            unsafe { gdal_sys::GDALGetAccess(shared.c_dataset()) },
+            GDALAccess::GA_Update as i32
+        );
+    }
+
+    #[test]
+    fn test_close_after_write() {
+        let output = InMemoryFixture::new("close.tif");
+        let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+        let dataset = driver
+            .create_with_band_type::<u8, _>(output.path(), 8, 8, 1)
+            .unwrap();
+        let mut buffer = Buffer::new((8, 8), vec![7u8; 64]);
+        dataset
+            .rasterband(1)
+            .unwrap()
+            .write((0, 0), (8, 8), &mut buffer)
+            .unwrap();
+        dataset.close().unwrap();
+
+        let dataset = Dataset::open(output.path()).unwrap();
+        let data = dataset
+            .rasterband(1)
+            .unwrap()
+            .read_as::<u8>((0, 0), (8, 8), (8, 8), None)
+            .unwrap();
+        assert!(data.data().iter().all(|&v| v == 7));
+    }
+
     #[test]
     fn test_open_ex_ro_vector() {
         Dataset::open_ex(
@@ -454,6 +796,82 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn test_open_ex_flatten_nested_attributes() {
+        let path = "/vsimem/test_open_ex_flatten_nested_attributes.geojson";
+        let geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [{
+                "type": "Feature",
+                "properties": { "address": { "city": "Paris", "zip": 75001 } },
+                "geometry": { "type": "Point", "coordinates": [2.35, 48.86] }
+            }]
+        }"#;
+        crate::vsi::create_mem_file(path, geojson.as_bytes().to_vec()).unwrap();
+
+        let field_names = |open_options: Option<&[&str]>| {
+            let ds = Dataset::open_ex(
+                path,
+                DatasetOptions {
+                    allowed_drivers: Some(&["GeoJSON"]),
+                    open_options,
+                    ..DatasetOptions::default()
+                },
+            )
+            .unwrap();
+            let layer = ds.layer(0).unwrap();
+            let names: Vec<String> = layer.defn().fields().map(|f| f.name()).collect();
+            names
+        };
+
+        assert_eq!(field_names(None), vec!["address"]);
+        assert_eq!(
+            field_names(Some(&["FLATTEN_NESTED_ATTRIBUTES=YES"])),
+            vec!["address_city", "address_zip"]
+        );
+
+        crate::vsi::unlink_mem_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_open_ex_overview_level() {
+        let ds = Dataset::open_ex(
+            fixture("tinymarble.tif"),
+            DatasetOptions {
+                open_options: Some(&["OVERVIEW_LEVEL=0"]),
+                ..DatasetOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(ds.raster_size(), (50, 25));
+    }
+
+    #[test]
+    fn test_open_ex_sibling_files() {
+        let without_ovr = Dataset::open_ex(
+            fixture("tinymarble.tif"),
+            DatasetOptions {
+                sibling_files: Some(&[]),
+                ..DatasetOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            without_ovr.rasterband(1).unwrap().overview_count().unwrap(),
+            0
+        );
+
+        let with_ovr = Dataset::open_ex(
+            fixture("tinymarble.tif"),
+            DatasetOptions {
+                sibling_files: Some(&["tinymarble.tif.ovr"]),
+                ..DatasetOptions::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(with_ovr.rasterband(1).unwrap().overview_count().unwrap(), 2);
+    }
+
     #[test]
     fn test_open_ex_extended_flags_vector() {
         Dataset::open_ex(
@@ -496,4 +914,42 @@ mod tests {
         let ds = Dataset::open(fixture("roads.geojson")).unwrap();
         assert_eq!(ds.raster_count(), 0);
     }
+
+    #[test]
+    fn test_raster_and_vector_gpkg() {
+        let output = InMemoryFixture::new("mixed.gpkg");
+        {
+            let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+            let mut ds = driver
+                .create_with_band_type::<u8, _>(output.path(), 8, 4, 1)
+                .unwrap();
+            ds.set_geo_transform(&[0.0, 1.0, 0.0, 4.0, 0.0, -1.0])
+                .unwrap();
+            ds.set_spatial_ref(&SpatialRef::from_epsg(4326).unwrap())
+                .unwrap();
+            let mut buffer = Buffer::new((8, 4), vec![1u8; 32]);
+            ds.rasterband(1)
+                .unwrap()
+                .write((0, 0), (8, 4), &mut buffer)
+                .unwrap();
+            ds.create_layer(LayerOptions {
+                name: "points",
+                ty: OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })
+            .unwrap();
+        }
+
+        let ds = Dataset::open(output.path()).unwrap();
+        assert_eq!(ds.raster_count(), 1);
+        assert_eq!(ds.raster_size(), (8, 4));
+        assert_eq!(ds.layer_count(), 1);
+        let names: Vec<_> = ds.layers().map(|layer| layer.name()).collect();
+        assert_eq!(names, ["points"]);
+
+        let raster = Dataset::open(fixture("tinymarble.tif")).unwrap();
+        assert!(raster.raster_count() > 0);
+        assert_eq!(raster.layer_count(), 0);
+        assert_eq!(raster.layers().count(), 0);
+    }
 }
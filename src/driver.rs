@@ -1,5 +1,6 @@
-use std::ffi::{c_int, CString};
+use std::ffi::{c_char, c_int, CString};
 use std::path::Path;
+use std::ptr;
 use std::sync::Once;
 
 use gdal_sys::{CPLErr, GDALDriverH, GDALMajorObjectH};
@@ -7,8 +8,9 @@ use gdal_sys::{CPLErr, GDALDriverH, GDALMajorObjectH};
 use crate::dataset::Dataset;
 use crate::gdal_major_object::MajorObject;
 use crate::metadata::Metadata;
+use crate::options::GdalOpenFlags;
 use crate::raster::{GdalDataType, GdalType, RasterCreationOptions};
-use crate::utils::{_last_cpl_err, _last_null_pointer_err, _path_to_c_string, _string};
+use crate::utils::{_last_cpl_err, _last_err_or_null_pointer, _path_to_c_string, _string};
 
 use crate::errors::*;
 
@@ -18,6 +20,35 @@ pub fn _register_drivers() {
     START.call_once(DriverManager::register_all);
 }
 
+/// Driver capabilities
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DriverCapability(&'static str);
+
+impl DriverCapability {
+    /// Driver can open existing datasets.
+    pub const OPEN: DriverCapability = DriverCapability("DCAP_OPEN");
+    /// Driver can create new datasets with [`Driver::create`].
+    pub const CREATE: DriverCapability = DriverCapability("DCAP_CREATE");
+    /// Driver can create datasets by copying another one with [`Dataset::create_copy`].
+    pub const CREATE_COPY: DriverCapability = DriverCapability("DCAP_CREATECOPY");
+    /// Driver handles raster data.
+    pub const RASTER: DriverCapability = DriverCapability("DCAP_RASTER");
+    /// Driver handles vector data.
+    pub const VECTOR: DriverCapability = DriverCapability("DCAP_VECTOR");
+    /// Driver handles multidimensional raster data.
+    pub const MULTIDIM_RASTER: DriverCapability = DriverCapability("DCAP_MULTIDIM_RASTER");
+    /// Driver handles geographic network models.
+    pub const GNM: DriverCapability = DriverCapability("DCAP_GNM");
+    /// Driver supports the virtual file system API, e.g. `/vsimem/` paths.
+    pub const VIRTUAL_IO: DriverCapability = DriverCapability("DCAP_VIRTUALIO");
+    /// Driver supports curve geometries.
+    pub const CURVE_GEOMETRIES: DriverCapability = DriverCapability("DCAP_CURVE_GEOMETRIES");
+    /// Driver supports geometries with a Z dimension.
+    pub const Z_GEOMETRIES: DriverCapability = DriverCapability("DCAP_Z_GEOMETRIES");
+    /// Driver supports geometries with an M dimension.
+    pub const MEASURED_GEOMETRIES: DriverCapability = DriverCapability("DCAP_MEASURED_GEOMETRIES");
+}
+
 /// # Raster and Vector Driver API
 ///
 /// One of GDAL's major strengths is the vast number of data formats it's able to work with.
@@ -39,6 +70,20 @@ pub struct Driver {
     c_driver: GDALDriverH,
 }
 
+/// Converts `strings` to a null-terminated array of C strings, along with the strings it points to.
+fn _c_string_list(strings: &[&str]) -> Result<(Vec<CString>, Vec<*const c_char>)> {
+    let c_strings = strings
+        .iter()
+        .map(|&s| CString::new(s))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let ptrs = c_strings
+        .iter()
+        .map(|s| s.as_ptr())
+        .chain(std::iter::once(ptr::null()))
+        .collect();
+    Ok((c_strings, ptrs))
+}
+
 impl Driver {
     /// Returns the driver with the given short name or [`Err`] if not found.
     #[deprecated(note = "Please use `DriverManager::get_driver_by_name()` instead")]
@@ -79,6 +124,106 @@ impl Driver {
         _string(rv).unwrap_or_default()
     }
 
+    /// Returns `true` if the driver advertises `capability` in its metadata.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::{DriverCapability, DriverManager};
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let writable_rasters = DriverManager::all()
+    ///     .filter(|d| d.has_capability(DriverCapability::RASTER))
+    ///     .filter(|d| d.has_capability(DriverCapability::CREATE))
+    ///     .map(|d| d.short_name())
+    ///     .collect::<Vec<_>>();
+    /// assert!(writable_rasters.contains(&"GTiff".to_string()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn has_capability(&self, capability: DriverCapability) -> bool {
+        self.metadata_item(capability.0, "")
+            .is_some_and(|v| v.eq_ignore_ascii_case("YES"))
+    }
+
+    /// Returns the main file extension used by the driver, e.g. `"tif"` for GeoTIFF,
+    /// or `None` if the driver doesn't declare one.
+    ///
+    /// See also: [`extensions`](Self::extensions).
+    pub fn extension(&self) -> Option<String> {
+        self.metadata_item("DMD_EXTENSION", "")
+            .filter(|e| !e.is_empty())
+    }
+
+    /// Returns all the file extensions handled by the driver, e.g. `["tif", "tiff"]` for
+    /// GeoTIFF. The result is empty if the driver doesn't declare any.
+    pub fn extensions(&self) -> Vec<String> {
+        self.metadata_item("DMD_EXTENSIONS", "")
+            .map(|e| e.split_whitespace().map(String::from).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the MIME type of the files written by the driver, e.g. `"image/tiff"`,
+    /// or `None` if the driver doesn't declare one.
+    pub fn mime_type(&self) -> Option<String> {
+        self.metadata_item("DMD_MIMETYPE", "")
+            .filter(|m| !m.is_empty())
+    }
+
+    /// Checks `options` against the creation options declared by the driver in its
+    /// `DMD_CREATIONOPTIONLIST` metadata item.
+    ///
+    /// Returns a [`GdalError::BadArgument`] listing the offending options if any are
+    /// unknown or have an invalid value, e.g. a misspelled `COMPRES=LZW` for GTiff.
+    /// Drivers which don't declare their creation options accept anything.
+    ///
+    /// If every option is valid on its own, but the driver rejects them together, the error
+    /// lists all of them.
+    ///
+    /// GDAL also reports the invalid options as warnings, once, which go through the
+    /// error handler; see [`set_error_handler`](crate::config::set_error_handler) to
+    /// capture or silence them.
+    ///
+    /// See: [`GDALValidateCreationOptions`](https://gdal.org/api/raster_c_api.html#_CPPv427GDALValidateCreationOptions11GDALDriverH12CSLConstList)
+    pub fn validate_creation_options(&self, options: &RasterCreationOptions) -> Result<()> {
+        let valid = |options: &RasterCreationOptions| unsafe {
+            gdal_sys::GDALValidateCreationOptions(self.c_driver, options.as_ptr()) != 0
+        };
+        if valid(options) {
+            return Ok(());
+        }
+
+        // Find the offending entries, without GDAL warning about each of them a second time
+        unsafe { gdal_sys::CPLPushErrorHandler(Some(gdal_sys::CPLQuietErrorHandler)) };
+        let invalid = options
+            .iter()
+            .map(|entry| {
+                let mut single = RasterCreationOptions::new();
+                single.add_entry(&entry)?;
+                Ok((!valid(&single)).then(|| entry.to_string()))
+            })
+            .filter_map(Result::transpose)
+            .collect::<Result<Vec<_>>>();
+        unsafe { gdal_sys::CPLPopErrorHandler() };
+
+        let invalid = invalid?;
+        let msg = if invalid.is_empty() {
+            // Every entry is fine on its own, but not together with the others
+            let all = options.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+            format!(
+                "combination of creation options rejected by driver {}: {}",
+                self.short_name(),
+                all.join(", ")
+            )
+        } else {
+            format!(
+                "invalid creation options for driver {}: {}",
+                self.short_name(),
+                invalid.join(", ")
+            )
+        };
+        Err(GdalError::BadArgument(msg))
+    }
+
     /// Create a new dataset of size (`size_x`, `size_y`) and `bands` band count,
     /// and [`u8`] as the cell data type.
     ///
@@ -143,9 +288,15 @@ impl Driver {
     /// Create a new dataset of size (`size_x`, `size_y`) and `bands` band count,
     /// with cell data type specified by `T` and extended options specified via `options`.
     /// [Per GDAL](https://gdal.org/api/gdaldriver_cpp.html#_CPPv4N10GDALDriver6CreateEPKciii12GDALDataType12CSLConstList),
-    /// the set of legal options for `options` is driver specific, and there is no way to query in advance to establish the valid ones.
+    /// the set of legal options for `options` is driver specific; [`validate_creation_options`](Self::validate_creation_options)
+    /// checks them against the options declared by the driver.
+    ///
+    /// Not all drivers support creating datasets from scratch: some, like PNG or AAIGrid, can only
+    /// write a copy of an existing dataset with [`Dataset::create_copy`]. For those this returns an
+    /// error; check for [`DriverCapability::CREATE`] beforehand to find out.
     ///
-    /// See also: [`RasterCreationOptions`], [`create`](Self::create), [`create_with_band_type`](Self::create_with_band_type).
+    /// See also: [`RasterCreationOptions`], [`create`](Self::create), [`create_with_band_type`](Self::create_with_band_type),
+    /// [`create_with_data_type`](Self::create_with_data_type).
     ///
     /// # Example
     ///
@@ -185,6 +336,30 @@ impl Driver {
         )
     }
 
+    /// Create a new dataset of size (`size_x`, `size_y`) and `bands` band count, with cell data
+    /// type `data_type` and extended options specified via `options`.
+    ///
+    /// This is the same as [`create_with_band_type_with_options`](Self::create_with_band_type_with_options),
+    /// for when the data type is only known at runtime.
+    pub fn create_with_data_type<P: AsRef<Path>>(
+        &self,
+        filename: P,
+        size_x: usize,
+        size_y: usize,
+        bands: usize,
+        data_type: GdalDataType,
+        options: &RasterCreationOptions,
+    ) -> Result<Dataset> {
+        self._create_with_band_type_with_options(
+            filename.as_ref(),
+            size_x,
+            size_y,
+            bands,
+            data_type,
+            options,
+        )
+    }
+
     fn _create_with_band_type_with_options(
         &self,
         filename: &Path,
@@ -200,6 +375,7 @@ impl Driver {
 
         let c_filename = _path_to_c_string(filename)?;
         let c_dataset = unsafe {
+            gdal_sys::CPLErrorReset();
             gdal_sys::GDALCreate(
                 self.c_driver,
                 c_filename.as_ptr(),
@@ -212,7 +388,7 @@ impl Driver {
         };
 
         if c_dataset.is_null() {
-            return Err(_last_null_pointer_err("GDALCreate"));
+            return Err(_last_err_or_null_pointer("GDALCreate"));
         };
 
         Ok(unsafe { Dataset::from_c_dataset(c_dataset) })
@@ -220,6 +396,9 @@ impl Driver {
 
     /// Convenience for creating a vector-only dataset from a compatible driver.
     /// [Details](https://gdal.org/api/gdaldriver_cpp.html#_CPPv4N10GDALDriver6CreateEPKciii12GDALDataType12CSLConstList)
+    ///
+    /// The dataset is created without raster bands, which GDAL treats as a vector dataset.
+    /// Add layers to it with [`Dataset::create_layer`], e.g. to write a GeoPackage from scratch.
     pub fn create_vector_only<P: AsRef<Path>>(&self, filename: P) -> Result<Dataset> {
         self._create_with_band_type_with_options(
             filename.as_ref(),
@@ -235,6 +414,9 @@ impl Driver {
     ///
     /// It is unwise to have open dataset handles on this dataset when it is deleted.
     ///
+    /// All the files making up the dataset are removed, such as the `.shx` and `.dbf`
+    /// of a shapefile, or the `.ovr` overviews and `.aux.xml` metadata of a raster.
+    ///
     /// Calls [`GDALDeleteDataset()`](https://gdal.org/api/raster_c_api.html#_CPPv417GDALDeleteDataset11GDALDriverHPKc)
     ///
     pub fn delete<P: AsRef<Path>>(&self, filename: P) -> Result<()> {
@@ -257,6 +439,8 @@ impl Driver {
     ///
     /// It is unwise to have open dataset handles on this dataset when it is being renamed.
     ///
+    /// Like [`delete`](Self::delete), this takes care of the side-car files of the dataset.
+    ///
     /// Calls [`GDALRenameDataset()`](https://gdal.org/api/raster_c_api.html#_CPPv417GDALRenameDataset11GDALDriverHPKcPKc)
     ///
     pub fn rename<P1: AsRef<Path>, P2: AsRef<Path>>(
@@ -286,6 +470,40 @@ impl Driver {
         Ok(())
     }
 
+    /// Copy all the files associated with a dataset.
+    ///
+    /// Unlike a plain file copy, this also copies side-car files, such as the `.shx`, `.dbf`
+    /// and `.prj` files of a Shapefile.
+    ///
+    /// Calls [`GDALCopyDatasetFiles()`](https://gdal.org/api/raster_c_api.html#_CPPv420GDALCopyDatasetFiles11GDALDriverHPKcPKc)
+    ///
+    pub fn copy_files<P1: AsRef<Path>, P2: AsRef<Path>>(
+        &self,
+        new_filename: P1,
+        old_filename: P2,
+    ) -> Result<()> {
+        Self::_copy_files(self, new_filename.as_ref(), old_filename.as_ref())
+    }
+
+    fn _copy_files(&self, new_filename: &Path, old_filename: &Path) -> Result<()> {
+        let c_old_filename = _path_to_c_string(old_filename)?;
+        let c_new_filename = _path_to_c_string(new_filename)?;
+
+        let rv = unsafe {
+            gdal_sys::GDALCopyDatasetFiles(
+                self.c_driver,
+                c_new_filename.as_ptr(),
+                c_old_filename.as_ptr(),
+            )
+        };
+
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+
+        Ok(())
+    }
+
     /// Creates a new Driver object by wrapping a C pointer
     ///
     /// # Safety
@@ -398,6 +616,57 @@ impl DriverManager {
         Ok(Driver { c_driver })
     }
 
+    /// Find the [`Driver`] that would open the dataset at `path`, without actually opening it.
+    ///
+    /// Returns `None` if no driver recognizes the file.
+    ///
+    /// # Arguments
+    /// * `flags` - restricts the drivers considered to the given kinds, e.g.
+    ///   [`GdalOpenFlags::GDAL_OF_VECTOR`]; other flags are ignored
+    /// * `allowed_drivers` - short names of the only drivers to consider
+    /// * `sibling_files` - names of the files next to `path`, to avoid probing the file
+    ///   system for them; see [`DatasetOptions::sibling_files`](crate::DatasetOptions::sibling_files)
+    ///
+    /// See: [`GDALIdentifyDriverEx`](https://gdal.org/api/raster_c_api.html#_CPPv420GDALIdentifyDriverExPKcjPPCKcPPCKc)
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::{DriverManager, GdalOpenFlags};
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let driver = DriverManager::identify_driver("upload.tif", GdalOpenFlags::empty(), None, None)?;
+    /// println!("{:?}", driver.map(|d| d.short_name()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn identify_driver<P: AsRef<Path>>(
+        path: P,
+        flags: GdalOpenFlags,
+        allowed_drivers: Option<&[&str]>,
+        sibling_files: Option<&[&str]>,
+    ) -> Result<Option<Driver>> {
+        _register_drivers();
+        let c_path = _path_to_c_string(path.as_ref())?;
+        let c_allowed_drivers = allowed_drivers.map(_c_string_list).transpose()?;
+        let c_sibling_files = sibling_files.map(_c_string_list).transpose()?;
+        let as_ptr = |list: &Option<(Vec<CString>, Vec<*const c_char>)>| {
+            list.as_ref().map_or(ptr::null(), |(_, ptrs)| ptrs.as_ptr())
+        };
+
+        let c_driver = unsafe {
+            gdal_sys::GDALIdentifyDriverEx(
+                c_path.as_ptr(),
+                flags.bits(),
+                as_ptr(&c_allowed_drivers),
+                as_ptr(&c_sibling_files),
+            )
+        };
+        if c_driver.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(Driver { c_driver }))
+    }
+
     /// Get one [`Driver`] that can create a file with the given name.
     ///
     /// Searches for registered drivers that can create files and support
@@ -609,6 +878,7 @@ mod tests {
     use std::collections::HashSet;
 
     use super::*;
+    use crate::test_utils::{fixture, SuppressGDALErrorLog};
 
     #[test]
     fn test_driver_access() {
@@ -620,6 +890,110 @@ mod tests {
         assert!(DriverManager::get_driver(0).is_ok());
     }
 
+    #[test]
+    fn test_validate_creation_options() {
+        let _nolog = SuppressGDALErrorLog::new();
+        let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+
+        let options = RasterCreationOptions::from_iter(["COMPRESS=LZW", "TILED=YES"]);
+        driver.validate_creation_options(&options).unwrap();
+
+        let options = RasterCreationOptions::from_iter(["COMPRES=LZW", "TILED=YES"]);
+        let err = driver.validate_creation_options(&options).unwrap_err();
+        match err {
+            GdalError::BadArgument(msg) => {
+                assert!(msg.contains("COMPRES=LZW"), "{msg}");
+                assert!(!msg.contains("TILED"), "{msg}");
+            }
+            _ => panic!("unexpected error: {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_driver_capabilities() {
+        let gtiff = DriverManager::get_driver_by_name("GTiff").unwrap();
+        assert!(gtiff.has_capability(DriverCapability::CREATE));
+        assert_eq!(
+            gtiff.metadata_item("DCAP_CREATE", "").as_deref(),
+            Some("YES")
+        );
+        assert!(gtiff.has_capability(DriverCapability::RASTER));
+        assert!(!gtiff.has_capability(DriverCapability::VECTOR));
+
+        // AAIGrid can only be written by copying an existing dataset.
+        let aaigrid = DriverManager::get_driver_by_name("AAIGrid").unwrap();
+        assert!(aaigrid.has_capability(DriverCapability::CREATE_COPY));
+        assert!(!aaigrid.has_capability(DriverCapability::CREATE));
+
+        let geojson = DriverManager::get_driver_by_name("GeoJSON").unwrap();
+        assert!(geojson.has_capability(DriverCapability::VECTOR));
+        assert!(!geojson.has_capability(DriverCapability::RASTER));
+    }
+
+    #[test]
+    fn test_driver_extensions() {
+        let gtiff = DriverManager::get_driver_by_name("GTiff").unwrap();
+        assert_eq!(gtiff.extension().as_deref(), Some("tif"));
+        assert_eq!(gtiff.extensions(), vec!["tif", "tiff"]);
+        assert_eq!(gtiff.mime_type().as_deref(), Some("image/tiff"));
+
+        let mem = DriverManager::get_driver_by_name("MEM").unwrap();
+        assert!(mem.extension().is_none());
+        assert!(mem.extensions().is_empty());
+        assert!(mem.mime_type().is_none());
+    }
+
+    #[test]
+    fn test_shapefile_copy_rename_delete() {
+        let dir = Path::new("/vsimem/driver_file_ops");
+        let dir_listing = || {
+            // Only look at the mandatory parts, the driver may add e.g. a `.cpg`.
+            let mut files = crate::vsi::read_dir(dir, false)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|p| p.to_string_lossy().into_owned())
+                .filter(|f| [".shp", ".shx", ".dbf"].iter().any(|e| f.ends_with(e)))
+                .collect::<Vec<_>>();
+            files.sort();
+            files
+        };
+
+        let driver = DriverManager::get_driver_by_name("ESRI Shapefile").unwrap();
+        {
+            let mut ds = driver.create_vector_only(dir.join("a.shp")).unwrap();
+            ds.create_layer(crate::vector::LayerOptions {
+                name: "a",
+                ty: gdal_sys::OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })
+            .unwrap();
+        }
+        assert_eq!(dir_listing(), vec!["a.dbf", "a.shp", "a.shx"]);
+
+        driver
+            .copy_files(dir.join("b.shp"), dir.join("a.shp"))
+            .unwrap();
+        assert_eq!(
+            dir_listing(),
+            vec!["a.dbf", "a.shp", "a.shx", "b.dbf", "b.shp", "b.shx"]
+        );
+
+        driver.rename(dir.join("c.shp"), dir.join("b.shp")).unwrap();
+        assert_eq!(
+            dir_listing(),
+            vec!["a.dbf", "a.shp", "a.shx", "c.dbf", "c.shp", "c.shx"]
+        );
+
+        driver.delete(dir.join("a.shp")).unwrap();
+        driver.delete(dir.join("c.shp")).unwrap();
+        assert!(crate::vsi::read_dir(dir, false)
+            .unwrap_or_default()
+            .is_empty());
+
+        let _nolog = crate::test_utils::SuppressGDALErrorLog::new();
+        assert!(driver.delete(dir.join("missing.shp")).is_err());
+    }
+
     #[test]
     fn test_driver_by_extension() {
         fn test_driver(d: &Driver, filename: &str, properties: DriverType) {
@@ -690,6 +1064,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_identify_driver() -> Result<()> {
+        let identify = |path: &str| {
+            DriverManager::identify_driver(fixture(path), GdalOpenFlags::empty(), None, None)
+                .unwrap()
+                .map(|d| d.short_name())
+        };
+        assert_eq!(identify("roads.geojson").as_deref(), Some("GeoJSON"));
+        assert_eq!(identify("tinymarble.tif").as_deref(), Some("GTiff"));
+        assert_eq!(identify("no_such_file.png"), None);
+
+        let raster_only = DriverManager::identify_driver(
+            fixture("roads.geojson"),
+            GdalOpenFlags::GDAL_OF_RASTER,
+            None,
+            None,
+        )?;
+        assert!(raster_only.is_none());
+
+        let allowed = DriverManager::identify_driver(
+            fixture("tinymarble.tif"),
+            GdalOpenFlags::empty(),
+            Some(&["PNG", "JPEG"]),
+            None,
+        )?;
+        assert!(allowed.is_none());
+        Ok(())
+    }
+
     #[test]
     fn test_driver_iterator() {
         assert_eq!(DriverManager::count(), DriverManager::all().count());
@@ -698,5 +1101,10 @@ mod tests {
         for i in 0..DriverManager::count() {
             assert!(drivers.contains(&DriverManager::get_driver(i).unwrap().short_name()))
         }
+
+        let gtiff = DriverManager::all()
+            .find(|d| d.short_name() == "GTiff")
+            .unwrap();
+        assert_eq!(gtiff.long_name(), "GeoTIFF");
     }
 }
@@ -86,6 +86,50 @@ pub enum GdalError {
     BufferSizeMismatch(usize, (usize, usize)),
 }
 
+/// Error numbers reported by GDAL, as found in the `number` of [`GdalError::CplError`].
+///
+/// These mirror the `CPLE_*` constants of [`cpl_error.h`]. Drivers may report other numbers.
+///
+/// [`cpl_error.h`]: https://gdal.org/api/cpl.html#cpl-error-h
+pub mod error_num {
+    use std::ffi::c_int;
+
+    /// No error.
+    pub const NONE: c_int = 0;
+    /// Application defined error, the most common one.
+    pub const APP_DEFINED: c_int = 1;
+    /// Out of memory error.
+    pub const OUT_OF_MEMORY: c_int = 2;
+    /// File I/O error.
+    pub const FILE_IO: c_int = 3;
+    /// Open failed, e.g. because the file doesn't exist.
+    pub const OPEN_FAILED: c_int = 4;
+    /// Illegal argument.
+    pub const ILLEGAL_ARG: c_int = 5;
+    /// Not supported.
+    pub const NOT_SUPPORTED: c_int = 6;
+    /// Assertion failed.
+    pub const ASSERTION_FAILED: c_int = 7;
+    /// No write access.
+    pub const NO_WRITE_ACCESS: c_int = 8;
+    /// User interrupted, e.g. through a progress callback.
+    pub const USER_INTERRUPT: c_int = 9;
+    /// `NULL` object.
+    pub const OBJECT_NULL: c_int = 10;
+    /// HTTP response error.
+    pub const HTTP_RESPONSE: c_int = 11;
+    /// AWS bucket not found.
+    pub const AWS_BUCKET_NOT_FOUND: c_int = 12;
+    /// AWS object not found.
+    pub const AWS_OBJECT_NOT_FOUND: c_int = 13;
+    /// AWS access denied.
+    pub const AWS_ACCESS_DENIED: c_int = 14;
+    /// AWS invalid credentials.
+    pub const AWS_INVALID_CREDENTIALS: c_int = 15;
+    /// AWS signature does not match.
+    pub const AWS_SIGNATURE_DOES_NOT_MATCH: c_int = 16;
+}
+
 /// A wrapper for [`CPLErr::Type`] that reflects it as an enum
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 #[repr(C)]
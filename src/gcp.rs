@@ -190,8 +190,7 @@ impl Dataset {
                     z: gcp.z,
                 })
             })
-            .collect::<Result<Vec<_>>>()
-            .unwrap();
+            .collect::<Result<Vec<_>>>()?;
         let gdal_gcps = c_gcps
             .iter()
             .map(|gcp| gdal_sys::GDAL_GCP {
@@ -225,9 +224,26 @@ impl Dataset {
 #[cfg(test)]
 mod tests {
     use super::Gcp;
+    use crate::errors::GdalError;
     use crate::spatial_ref::SpatialRef;
     use crate::test_utils::{fixture, TempFixture};
-    use crate::Dataset;
+    use crate::{Dataset, DriverManager};
+
+    fn corner_gcps() -> Vec<Gcp> {
+        [(0.0, 0.0), (20.0, 0.0), (20.0, 10.0), (0.0, 10.0)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (pixel, line))| Gcp {
+                id: (i + 1).to_string(),
+                info: String::new(),
+                pixel,
+                line,
+                x: 500_000.0 + pixel * 30.0,
+                y: 4_100_000.0 - line * 30.0,
+                z: 0.0,
+            })
+            .collect()
+    }
 
     #[test]
     fn test_gcp_spatial_ref() {
@@ -302,4 +318,33 @@ mod tests {
         assert_eq!(spatial_ref.auth_name().unwrap(), "EPSG");
         assert_eq!(spatial_ref.auth_code().unwrap(), 3857);
     }
+
+    #[test]
+    #[cfg_attr(not(all(major_ge_3, minor_ge_5)), ignore)]
+    fn test_set_gcps_mem() {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let dataset = driver.create("", 20, 10, 1).unwrap();
+        assert!(dataset.gcps().is_empty());
+
+        let gcps = corner_gcps();
+        let spatial_ref = SpatialRef::from_epsg(32611).unwrap();
+        dataset.set_gcps(gcps.clone(), &spatial_ref).unwrap();
+
+        let read_back = dataset.gcps().iter().map(Gcp::from).collect::<Vec<_>>();
+        assert_eq!(read_back, gcps);
+        assert!(dataset.gcp_projection().unwrap().contains("UTM zone 11N"));
+    }
+
+    #[test]
+    fn test_set_gcps_invalid_id() {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let dataset = driver.create("", 20, 10, 1).unwrap();
+        let mut gcps = corner_gcps();
+        gcps[0].id = "bad\0id".to_owned();
+        let spatial_ref = SpatialRef::from_epsg(32611).unwrap();
+
+        let result = dataset.set_gcps(gcps, &spatial_ref);
+        assert!(matches!(result, Err(GdalError::FfiNulError(_))));
+        assert!(dataset.gcps().is_empty());
+    }
 }
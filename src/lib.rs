@@ -118,6 +118,7 @@ mod options;
 pub mod programs;
 pub mod raster;
 pub mod spatial_ref;
+mod subdataset;
 #[cfg(test)]
 pub mod test_utils;
 mod utils;
@@ -129,11 +130,12 @@ pub use dataset::Dataset;
 pub use geo_transform::{GeoTransform, GeoTransformEx};
 pub use options::{DatasetOptions, GdalOpenFlags};
 
-pub use driver::{Driver, DriverManager, DriverType};
+pub use driver::{Driver, DriverCapability, DriverManager, DriverType};
 pub use gcp::{Gcp, GcpRef};
 #[cfg(any(major_ge_4, all(major_is_3, minor_ge_6)))]
 pub use gdal_sys::ArrowArrayStream;
 pub use metadata::{Metadata, MetadataEntry};
+pub use subdataset::Subdataset;
 
 #[cfg(test)]
 fn assert_almost_eq(a: f64, b: f64) {
@@ -296,7 +296,8 @@ impl Iterator for MetadataIter<'_> {
 #[cfg(test)]
 mod tests {
     use crate::metadata::MetadataEntry;
-    use crate::test_utils::fixture;
+    use crate::raster::RasterCreationOptions;
+    use crate::test_utils::{fixture, InMemoryFixture};
     use crate::*;
 
     #[test]
@@ -344,6 +345,73 @@ mod tests {
         assert_eq!(meta, Some(vec!(String::from("INTERLEAVE=PIXEL"))));
     }
 
+    #[test]
+    fn test_get_subdatasets_domain() {
+        let output = InMemoryFixture::new("two_pages.tif");
+        let mem = DriverManager::get_driver_by_name("MEM").unwrap();
+        let gtiff = DriverManager::get_driver_by_name("GTiff").unwrap();
+        let first = mem.create("", 4, 4, 1).unwrap();
+        first
+            .create_copy(&gtiff, output.path(), &RasterCreationOptions::new())
+            .unwrap();
+        let second = mem.create("", 2, 2, 1).unwrap();
+        second
+            .create_copy(
+                &gtiff,
+                output.path(),
+                &RasterCreationOptions::from_iter(["APPEND_SUBDATASET=YES"]),
+            )
+            .unwrap();
+
+        let dataset = Dataset::open(output.path()).unwrap();
+        assert!(dataset
+            .metadata_domains()
+            .contains(&"SUBDATASETS".to_string()));
+
+        let path = output.path().to_str().unwrap();
+        let names = dataset
+            .metadata_domain("SUBDATASETS")
+            .unwrap()
+            .into_iter()
+            .filter_map(|entry| {
+                let (key, value) = entry.split_once('=')?;
+                key.ends_with("_NAME").then(|| value.to_string())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![format!("GTIFF_DIR:1:{path}"), format!("GTIFF_DIR:2:{path}")]
+        );
+
+        let page = Dataset::open(&names[1]).unwrap();
+        assert_eq!(page.raster_size(), (2, 2));
+    }
+
+    #[test]
+    fn test_get_image_structure_compression() {
+        let output = InMemoryFixture::new("compressed.tif");
+        let driver = DriverManager::get_driver_by_name("GTiff").unwrap();
+        driver
+            .create_with_band_type_with_options::<u8, _>(
+                output.path(),
+                16,
+                16,
+                1,
+                &RasterCreationOptions::from_iter(["COMPRESS=LZW"]),
+            )
+            .unwrap();
+
+        let dataset = Dataset::open(output.path()).unwrap();
+        assert_eq!(
+            dataset.metadata_item("COMPRESSION", "IMAGE_STRUCTURE"),
+            Some("LZW".to_string())
+        );
+        assert!(dataset
+            .metadata_domain("IMAGE_STRUCTURE")
+            .unwrap()
+            .contains(&"COMPRESSION=LZW".to_string()));
+    }
+
     #[test]
     fn test_get_metadata_item() {
         let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
@@ -6,9 +6,16 @@ use gdal_sys::GDALAccess;
 /// Open options for [`crate::Dataset`]
 #[derive(Debug, Default)]
 pub struct DatasetOptions<'a> {
+    /// Access mode and kinds of drivers to consider.
     pub open_flags: GdalOpenFlags,
+    /// Short names of the drivers allowed to open the dataset, e.g. `["GeoJSON"]`.
+    /// When `None`, every registered driver is tried.
     pub allowed_drivers: Option<&'a [&'a str]>,
+    /// Driver-specific open options, in the form `name=value`, e.g. `["OVERVIEW_LEVEL=0"]`.
     pub open_options: Option<&'a [&'a str]>,
+    /// Names of the files next to the dataset, used by drivers to find side-car files
+    /// (`.ovr`, `.aux.xml`, ...) without listing the directory. An empty slice means
+    /// there are no such files; `None` lets GDAL probe the file system.
     pub sibling_files: Option<&'a [&'a str]>,
 }
 
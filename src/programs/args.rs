@@ -0,0 +1,41 @@
+use std::{
+    ffi::{c_char, CString},
+    ptr::null_mut,
+};
+
+use crate::{errors::*, utils::_last_err_or_null_pointer};
+
+/// Create a `GDAL*Options` object from the command line arguments `args` of a program.
+///
+/// `options_new` is handed the null-terminated argument vector and should pass it on
+/// to the `GDAL*OptionsNew` function named `method_name`. GDAL copies what it needs
+/// out of the vector, so it only has to live for the duration of the call.
+pub(crate) fn new_options<T, S, I>(
+    args: I,
+    method_name: &'static str,
+    options_new: impl FnOnce(*mut *mut c_char) -> *mut T,
+) -> Result<*mut T>
+where
+    S: Into<Vec<u8>>,
+    I: IntoIterator<Item = S>,
+{
+    // Convert args to CStrings to add terminating null bytes
+    let cstr_args = args
+        .into_iter()
+        .map(CString::new)
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    // These strings don't actually get modified, the C API is just not const-correct
+    let mut c_args = cstr_args
+        .iter()
+        .map(|x| x.as_ptr() as *mut c_char)
+        .chain(std::iter::once(null_mut()))
+        .collect::<Vec<_>>();
+
+    unsafe { gdal_sys::CPLErrorReset() };
+    let c_options = options_new(c_args.as_mut_ptr());
+    if c_options.is_null() {
+        return Err(_last_err_or_null_pointer(method_name));
+    }
+    Ok(c_options)
+}
@@ -1,3 +1,9 @@
 //! Rust wrappers for the [GDAL Programs](https://gdal.org/programs/index.html)
 
+mod args;
+mod progress;
 pub mod raster;
+pub mod vector;
+
+pub(crate) use args::new_options;
+pub(crate) use progress::ProgressCallback;
@@ -0,0 +1,52 @@
+use std::ffi::{c_char, c_int, c_void, CStr};
+
+type ProgressFn = Box<dyn FnMut(f64, &str) -> bool>;
+
+/// Owns a user supplied progress closure and exposes it as a `GDALProgressFunc`.
+///
+/// The closure is boxed twice so that the data pointer handed to GDAL is thin and
+/// stays valid for as long as this value is alive.
+pub(crate) struct ProgressCallback(Box<ProgressFn>);
+
+impl ProgressCallback {
+    pub(crate) fn new<F: FnMut(f64, &str) -> bool + 'static>(callback: F) -> Self {
+        Self(Box::new(Box::new(callback)))
+    }
+
+    /// The function to register with GDAL, together with [`Self::data`].
+    pub(crate) fn func(&self) -> gdal_sys::GDALProgressFunc {
+        Some(trampoline)
+    }
+
+    /// The `pProgressData` argument to pass alongside [`Self::func`].
+    pub(crate) fn data(&mut self) -> *mut c_void {
+        self.0.as_mut() as *mut ProgressFn as *mut c_void
+    }
+
+    /// Install `callback` on `c_options` with the `GDAL*OptionsSetProgress` function
+    /// `set_progress`.
+    ///
+    /// The returned value must be kept alive for as long as `c_options` may report progress.
+    ///
+    /// # Safety
+    /// `c_options` must be a valid options object of the type `set_progress` expects.
+    pub(crate) unsafe fn register<T, F: FnMut(f64, &str) -> bool + 'static>(
+        c_options: *mut T,
+        set_progress: unsafe extern "C" fn(*mut T, gdal_sys::GDALProgressFunc, *mut c_void),
+        callback: F,
+    ) -> Self {
+        let mut progress = Self::new(callback);
+        set_progress(c_options, progress.func(), progress.data());
+        progress
+    }
+}
+
+unsafe extern "C" fn trampoline(complete: f64, message: *const c_char, data: *mut c_void) -> c_int {
+    let callback = &mut *(data as *mut ProgressFn);
+    let message = if message.is_null() {
+        ""
+    } else {
+        CStr::from_ptr(message).to_str().unwrap_or_default()
+    };
+    callback(complete, message) as c_int
+}
@@ -0,0 +1,103 @@
+use std::path::Path;
+
+use crate::{cpl::CslStringList, errors::*, raster::processing::dem::dem_eval, Dataset};
+
+/// The `gdaldem` processing modes, the same enum as
+/// [`crate::raster::processing::dem::DemAlg`].
+pub use crate::raster::processing::dem::DemAlg as DemProcessing;
+
+/// Run the `gdaldem` processor `processing` on `src`, writing the result to `dest`.
+/// Wraps [GDALDEMProcessing].
+/// See the [program docs] for more details.
+///
+/// `options` are the command line arguments of `gdaldem` that follow the input and output
+/// files, e.g. `["-z", "2", "-az", "330", "-alt", "45"]` for [`DemProcessing::Hillshade`]. Unlike the typed routines in
+/// [`crate::raster::processing::dem`], this covers every option of every mode.
+///
+/// `color_filename` is the color text file that maps elevations to colors. It is required by
+/// [`DemProcessing::ColorRelief`], and ignored by the other modes.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # fn main() -> gdal::errors::Result<()> {
+/// use gdal::programs::raster::{dem_processing, DemProcessing};
+/// use gdal::Dataset;
+/// let ds = Dataset::open("fixtures/dem-hills.tiff")?;
+/// let opts = ["-z", "2", "-az", "330", "-alt", "45"];
+/// let shade = dem_processing(&ds, "target/dem-hills-shade.tiff", DemProcessing::Hillshade, None, &opts)?;
+/// assert_eq!(shade.raster_size(), ds.raster_size());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [GDALDEMProcessing]: https://gdal.org/api/gdal_utils.html#_CPPv417GDALDEMProcessingPKc12GDALDatasetHPKcPKcPK24GDALDEMProcessingOptionsPi
+/// [program docs]: https://gdal.org/programs/gdaldem.html
+pub fn dem_processing<P: AsRef<Path>>(
+    src: &Dataset,
+    dest: P,
+    processing: DemProcessing,
+    color_filename: Option<&str>,
+    options: &[&str],
+) -> Result<Dataset> {
+    let mut args = CslStringList::new();
+    for arg in options {
+        args.add_string(arg)?;
+    }
+    dem_eval(
+        src,
+        dest.as_ref(),
+        processing,
+        &args,
+        color_filename.map(Path::new),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{fixture, ramp_dem, InMemoryFixture, SuppressGDALErrorLog};
+
+    #[test]
+    fn test_dem_processing_hillshade() -> Result<()> {
+        let ds = ramp_dem()?;
+        let opts = ["-z", "2", "-az", "90", "-alt", "45"];
+        let output = InMemoryFixture::new("ramp-shade.tiff");
+        let shade = dem_processing(&ds, output.path(), DemProcessing::Hillshade, None, &opts)?;
+        assert_eq!(shade.raster_size(), ds.raster_size());
+        assert_eq!(shade.raster_count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dem_processing_color_relief() -> Result<()> {
+        let ds = Dataset::open(fixture("dem-hills.tiff"))?;
+        let output = InMemoryFixture::new("dem-hills-relief.tiff");
+        let relief = dem_processing(
+            &ds,
+            output.path(),
+            DemProcessing::ColorRelief,
+            fixture("color-relief.clr").to_str(),
+            &[],
+        )?;
+        assert_eq!(relief.raster_size(), ds.raster_size());
+        assert_eq!(relief.raster_count(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dem_processing_missing_color_config() -> Result<()> {
+        let _nolog = SuppressGDALErrorLog::new();
+        let ds = ramp_dem()?;
+        // no output file is created on failure, so `InMemoryFixture` isn't needed
+        let res = dem_processing(
+            &ds,
+            "/vsimem/ramp-relief.tiff",
+            DemProcessing::ColorRelief,
+            None,
+            &[],
+        );
+        assert!(res.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+use std::{path::Path, ptr::null_mut};
+
+use gdal_sys::GDALGridOptions;
+
+use crate::{
+    errors::*,
+    programs::{new_options, ProgressCallback},
+    utils::{_last_err_or_null_pointer, _path_to_c_string},
+    Dataset,
+};
+
+/// Wraps a [GDALGridOptions] object.
+///
+/// [GDALGridOptions]: https://gdal.org/api/gdal_utils.html#_CPPv415GDALGridOptions
+pub struct GridOptions {
+    c_options: *mut GDALGridOptions,
+    progress: Option<ProgressCallback>,
+}
+
+impl GridOptions {
+    /// See [GDALGridOptionsNew].
+    ///
+    /// [GDALGridOptionsNew]: https://gdal.org/api/gdal_utils.html#_CPPv418GDALGridOptionsNewPPcP24GDALGridOptionsForBinary
+    ///
+    /// `args` are the command line arguments of [`gdal_grid`], e.g.
+    /// `["-a", "invdist:power=2.0:smoothing=1.0", "-zfield", "height", "-outsize", "100", "100"]`.
+    /// An unknown `-a` algorithm or algorithm parameter is rejected here, with the
+    /// message `gdal_grid` would print.
+    ///
+    /// [`gdal_grid`]: https://gdal.org/programs/gdal_grid.html
+    pub fn new<S: Into<Vec<u8>>, I: IntoIterator<Item = S>>(args: I) -> Result<Self> {
+        let c_options = new_options(args, "GDALGridOptionsNew", |c_args| unsafe {
+            gdal_sys::GDALGridOptionsNew(c_args, null_mut())
+        })?;
+        Ok(Self {
+            c_options,
+            progress: None,
+        })
+    }
+
+    /// Report progress of the interpolation to `callback`.
+    ///
+    /// The callback receives the completed fraction, between `0.0` and `1.0`, and
+    /// a possibly empty status message. Returning `false` cancels the interpolation,
+    /// in which case [`grid`] returns an error.
+    ///
+    /// See [GDALGridOptionsSetProgress].
+    ///
+    /// [GDALGridOptionsSetProgress]: https://gdal.org/api/gdal_utils.html#_CPPv426GDALGridOptionsSetProgressP15GDALGridOptions16GDALProgressFuncPv
+    pub fn set_progress<F: FnMut(f64, &str) -> bool + 'static>(&mut self, callback: F) {
+        let progress = unsafe {
+            ProgressCallback::register(
+                self.c_options,
+                gdal_sys::GDALGridOptionsSetProgress,
+                callback,
+            )
+        };
+        self.progress = Some(progress);
+    }
+
+    /// Returns the wrapped C pointer
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_options(&self) -> *mut GDALGridOptions {
+        self.c_options
+    }
+}
+
+impl Drop for GridOptions {
+    fn drop(&mut self) {
+        unsafe {
+            gdal_sys::GDALGridOptionsFree(self.c_options);
+        }
+    }
+}
+
+impl TryFrom<Vec<&str>> for GridOptions {
+    type Error = GdalError;
+
+    fn try_from(value: Vec<&str>) -> Result<Self> {
+        GridOptions::new(value)
+    }
+}
+
+/// Interpolate the scattered points of the vector dataset `src` into a new raster
+/// dataset at `dest`.
+/// Wraps [GDALGrid].
+/// See the [program docs] for more details.
+///
+/// Point values are taken from the Z coordinate unless `-zfield` is given. The
+/// default algorithm is inverse distance to a power; see `-a` for the others.
+/// The output driver is the one named by `-of`, or else the one matching the
+/// extension of `dest`.
+///
+/// [GDALGrid]: https://gdal.org/api/gdal_utils.html#_CPPv48GDALGridPKc12GDALDatasetHPK15GDALGridOptionsPi
+/// [program docs]: https://gdal.org/programs/gdal_grid.html
+pub fn grid<P: AsRef<Path>>(
+    src: &Dataset,
+    dest: P,
+    options: Option<GridOptions>,
+) -> Result<Dataset> {
+    _grid(src, dest.as_ref(), options)
+}
+
+fn _grid(src: &Dataset, dest: &Path, options: Option<GridOptions>) -> Result<Dataset> {
+    let c_dest = _path_to_c_string(dest)?;
+
+    let c_options = options
+        .as_ref()
+        .map(|x| x.c_options as *const GDALGridOptions)
+        .unwrap_or(std::ptr::null());
+
+    let dataset_out = unsafe {
+        gdal_sys::CPLErrorReset();
+        gdal_sys::GDALGrid(c_dest.as_ptr(), src.c_dataset(), c_options, null_mut())
+    };
+
+    if dataset_out.is_null() {
+        return Err(_last_err_or_null_pointer("GDALGrid"));
+    }
+
+    let result = unsafe { Dataset::from_c_dataset(dataset_out) };
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_utils::{memory_dataset, memory_layer, SuppressGDALErrorLog};
+    use crate::vector::{Geometry, LayerAccess, OGRwkbGeometryType};
+
+    /// Four 3D points at the corners of `(0, 0)` to `(10, 10)`, with heights 0, 10, 10 and 20.
+    fn corner_points() -> Result<Dataset> {
+        let mut ds = memory_dataset()?;
+        {
+            let mut layer = memory_layer(&mut ds, "points", OGRwkbGeometryType::wkbPoint25D, &[])?;
+            for wkt in [
+                "POINT Z (0 0 0)",
+                "POINT Z (10 0 10)",
+                "POINT Z (0 10 10)",
+                "POINT Z (10 10 20)",
+            ] {
+                layer.create_feature(Geometry::from_wkt(wkt)?)?;
+            }
+        }
+        Ok(ds)
+    }
+
+    fn grid_values(ds: &Dataset) -> Vec<f64> {
+        let (cols, rows) = ds.raster_size();
+        let band = ds.rasterband(1).unwrap();
+        band.read_as::<f64>((0, 0), (cols, rows), (cols, rows), None)
+            .unwrap()
+            .data()
+            .to_vec()
+    }
+
+    #[test]
+    fn test_grid_nearest() -> Result<()> {
+        let src = corner_points()?;
+        let options = vec![
+            "-of", "MEM", "-ot", "Float64", "-a", "nearest", "-txe", "0", "10", "-tye", "0", "10",
+            "-outsize", "10", "10",
+        ]
+        .try_into()?;
+        let ds = grid(&src, "", Some(options))?;
+        assert_eq!(ds.raster_size(), (10, 10));
+
+        // each corner pixel takes the value of the closest input point
+        let values = grid_values(&ds);
+        let mut corners = [values[0], values[9], values[90], values[99]];
+        corners.sort_by(f64::total_cmp);
+        assert_eq!(corners, [0.0, 10.0, 10.0, 20.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_grid_invdist() -> Result<()> {
+        let src = corner_points()?;
+        let options = GridOptions::new([
+            "-of",
+            "MEM",
+            "-ot",
+            "Float64",
+            "-a",
+            "invdist:power=2",
+            "-txe",
+            "0",
+            "10",
+            "-tye",
+            "0",
+            "10",
+            "-outsize",
+            "8",
+            "4",
+        ])?;
+        let ds = grid(&src, "", Some(options))?;
+        assert_eq!(ds.raster_size(), (8, 4));
+
+        let values = grid_values(&ds);
+        assert!(values.iter().all(|v| (0.0..=20.0).contains(v)));
+        // the surface is symmetric, so it averages to the mean of the inputs
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        assert!((mean - 10.0).abs() < 1e-6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_grid_options_invalid() {
+        let _nolog = SuppressGDALErrorLog::new();
+        assert!(GridOptions::new(["-a", "bogus"]).is_err());
+    }
+}
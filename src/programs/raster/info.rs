@@ -0,0 +1,127 @@
+use std::{
+    ffi::c_void,
+    ptr::{null, null_mut},
+};
+
+use gdal_sys::GDALInfoOptions;
+
+use crate::{
+    errors::*,
+    programs::new_options,
+    utils::{_last_err_or_null_pointer, _string},
+    Dataset,
+};
+
+/// Wraps a [GDALInfoOptions] object.
+///
+/// [GDALInfoOptions]: https://gdal.org/api/gdal_utils.html#_CPPv415GDALInfoOptions
+pub struct InfoOptions {
+    c_options: *mut GDALInfoOptions,
+}
+
+impl InfoOptions {
+    /// See [GDALInfoOptionsNew].
+    ///
+    /// [GDALInfoOptionsNew]: https://gdal.org/api/gdal_utils.html#_CPPv418GDALInfoOptionsNewPPcP24GDALInfoOptionsForBinary
+    ///
+    /// `args` are the command line arguments of [`gdalinfo`], e.g.
+    /// `["-json", "-stats", "-hist"]`.
+    /// The statistics requested by `-stats` or `-hist` are computed when [`info`]
+    /// runs, not here.
+    ///
+    /// [`gdalinfo`]: https://gdal.org/programs/gdalinfo.html
+    pub fn new<S: Into<Vec<u8>>, I: IntoIterator<Item = S>>(args: I) -> Result<Self> {
+        let c_options = new_options(args, "GDALInfoOptionsNew", |c_args| unsafe {
+            gdal_sys::GDALInfoOptionsNew(c_args, null_mut())
+        })?;
+        Ok(Self { c_options })
+    }
+
+    /// Returns the wrapped C pointer
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_options(&self) -> *mut GDALInfoOptions {
+        self.c_options
+    }
+}
+
+impl Drop for InfoOptions {
+    fn drop(&mut self) {
+        unsafe {
+            gdal_sys::GDALInfoOptionsFree(self.c_options);
+        }
+    }
+}
+
+impl TryFrom<Vec<&str>> for InfoOptions {
+    type Error = GdalError;
+
+    fn try_from(value: Vec<&str>) -> Result<Self> {
+        InfoOptions::new(value)
+    }
+}
+
+/// Describe a raster dataset, as the `gdalinfo` utility would.
+/// Wraps [GDALInfo].
+/// See the [program docs] for more details.
+///
+/// By default the report is plain text. With `-json` it is a JSON document
+/// covering the driver, size, coordinate system, geotransform, metadata and bands.
+///
+/// Note that `-stats` and `-hist` may store the computed values in a `.aux.xml`
+/// file next to the dataset.
+///
+/// [GDALInfo]: https://gdal.org/api/gdal_utils.html#_CPPv48GDALInfo12GDALDatasetHPK15GDALInfoOptions
+/// [program docs]: https://gdal.org/programs/gdalinfo.html
+pub fn info(ds: &Dataset, options: Option<InfoOptions>) -> Result<String> {
+    let c_options = options
+        .as_ref()
+        .map(|x| x.c_options as *const GDALInfoOptions)
+        .unwrap_or(null());
+
+    let c_info = unsafe {
+        gdal_sys::CPLErrorReset();
+        gdal_sys::GDALInfo(ds.c_dataset(), c_options)
+    };
+    if c_info.is_null() {
+        return Err(_last_err_or_null_pointer("GDALInfo"));
+    }
+    let info = _string(c_info).unwrap_or_default();
+    unsafe { gdal_sys::VSIFree(c_info as *mut c_void) };
+
+    Ok(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_utils::{fixture, SuppressGDALErrorLog};
+
+    #[test]
+    fn test_info_text() {
+        let ds = Dataset::open(fixture("tinymarble.tif")).unwrap();
+        let report = info(&ds, None).unwrap();
+        assert!(report.contains("Driver: GTiff/GeoTIFF"));
+        assert!(report.contains("Size is 100, 50"));
+    }
+
+    #[test]
+    fn test_info_json() {
+        let ds = Dataset::open(fixture("tinymarble.tif")).unwrap();
+        let options = vec!["-json", "-mm"].try_into().unwrap();
+        let report = info(&ds, Some(options)).unwrap();
+        assert!(report.trim_start().starts_with('{'));
+        assert!(report.trim_end().ends_with('}'));
+        assert!(report.contains("\"bands\""));
+        assert_eq!(report.matches("\"band\"").count(), ds.raster_count());
+        assert!(report.contains("\"computedMin\""));
+    }
+
+    #[test]
+    fn test_info_options_invalid() {
+        let _nolog = SuppressGDALErrorLog::new();
+        assert!(InfoOptions::new(["-bogus"]).is_err());
+    }
+}
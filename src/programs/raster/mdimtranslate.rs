@@ -10,7 +10,7 @@ use gdal_sys::{GDALMultiDimTranslate, GDALMultiDimTranslateOptions};
 
 use crate::{
     errors::*,
-    utils::{_last_null_pointer_err, _path_to_c_string},
+    utils::{_last_err_or_null_pointer, _path_to_c_string},
     Dataset,
 };
 
@@ -199,6 +199,7 @@ fn _multi_dim_translate(
     let mut pb_usage_error: c_int = 0;
 
     let dataset_out = unsafe {
+        gdal_sys::CPLErrorReset();
         let data = GDALMultiDimTranslate(
             psz_dest,
             h_dst_ds,
@@ -215,7 +216,7 @@ fn _multi_dim_translate(
     };
 
     if dataset_out.is_null() {
-        return Err(_last_null_pointer_err("GDALMultiDimTranslate"));
+        return Err(_last_err_or_null_pointer("GDALMultiDimTranslate"));
     }
 
     let result = unsafe { Dataset::from_c_dataset(dataset_out) };
@@ -1,7 +1,19 @@
+mod dem;
+mod grid;
+mod info;
 mod mdimtranslate;
+mod rasterize;
+mod translate;
 mod vrt;
+mod warp;
 
+pub use dem::*;
+pub use grid::*;
+pub use info::*;
 pub use mdimtranslate::{
     multi_dim_translate, MultiDimTranslateDestination, MultiDimTranslateOptions,
 };
+pub use rasterize::*;
+pub use translate::*;
 pub use vrt::*;
+pub use warp::*;
@@ -0,0 +1,265 @@
+use std::{path::Path, ptr::null_mut};
+
+use gdal_sys::GDALRasterizeOptions;
+
+use crate::{
+    errors::*,
+    programs::{new_options, ProgressCallback},
+    utils::{_last_err_or_null_pointer, _path_to_c_string},
+    Dataset,
+};
+
+/// Wraps a [GDALRasterizeOptions] object.
+///
+/// Not to be confused with [`crate::raster::RasterizeOptions`], which configures
+/// [`crate::raster::rasterize`] for burning individual geometries.
+///
+/// [GDALRasterizeOptions]: https://gdal.org/api/gdal_utils.html#_CPPv420GDALRasterizeOptions
+pub struct RasterizeAppOptions {
+    c_options: *mut GDALRasterizeOptions,
+    progress: Option<ProgressCallback>,
+}
+
+impl RasterizeAppOptions {
+    /// See [GDALRasterizeOptionsNew].
+    ///
+    /// [GDALRasterizeOptionsNew]: https://gdal.org/api/gdal_utils.html#_CPPv423GDALRasterizeOptionsNewPPcP29GDALRasterizeOptionsForBinary
+    ///
+    /// `args` are the command line arguments of [`gdal_rasterize`], e.g.
+    /// `["-l", "parcels", "-a", "zone", "-where", "zone > 0"]` or `["-burn", "255"]`.
+    /// One of `-burn`, `-a` or `-3d` is needed to choose the values that are burnt.
+    ///
+    /// [`gdal_rasterize`]: https://gdal.org/programs/gdal_rasterize.html
+    pub fn new<S: Into<Vec<u8>>, I: IntoIterator<Item = S>>(args: I) -> Result<Self> {
+        let c_options = new_options(args, "GDALRasterizeOptionsNew", |c_args| unsafe {
+            gdal_sys::GDALRasterizeOptionsNew(c_args, null_mut())
+        })?;
+        Ok(Self {
+            c_options,
+            progress: None,
+        })
+    }
+
+    /// Report progress of the rasterization to `callback`.
+    ///
+    /// The callback receives the completed fraction, between `0.0` and `1.0`, and
+    /// a possibly empty status message. Returning `false` cancels the rasterization,
+    /// in which case [`rasterize`] or [`rasterize_create`] return an error.
+    ///
+    /// See [GDALRasterizeOptionsSetProgress].
+    ///
+    /// [GDALRasterizeOptionsSetProgress]: https://gdal.org/api/gdal_utils.html#_CPPv431GDALRasterizeOptionsSetProgressP20GDALRasterizeOptions16GDALProgressFuncPv
+    pub fn set_progress<F: FnMut(f64, &str) -> bool + 'static>(&mut self, callback: F) {
+        let progress = unsafe {
+            ProgressCallback::register(
+                self.c_options,
+                gdal_sys::GDALRasterizeOptionsSetProgress,
+                callback,
+            )
+        };
+        self.progress = Some(progress);
+    }
+
+    /// Returns the wrapped C pointer
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_options(&self) -> *mut GDALRasterizeOptions {
+        self.c_options
+    }
+}
+
+impl Drop for RasterizeAppOptions {
+    fn drop(&mut self) {
+        unsafe {
+            gdal_sys::GDALRasterizeOptionsFree(self.c_options);
+        }
+    }
+}
+
+impl TryFrom<Vec<&str>> for RasterizeAppOptions {
+    type Error = GdalError;
+
+    fn try_from(value: Vec<&str>) -> Result<Self> {
+        RasterizeAppOptions::new(value)
+    }
+}
+
+/// Burn the vector features of `src` into the existing raster dataset `dst`.
+/// Wraps [GDALRasterize].
+/// See the [program docs] for more details.
+///
+/// Options that create a new dataset, such as `-of`, `-ts` or `-te`, are not
+/// allowed here; use [`rasterize_create`] instead.
+///
+/// [GDALRasterize]: https://gdal.org/api/gdal_utils.html#_CPPv413GDALRasterizePKc12GDALDatasetH12GDALDatasetHPK20GDALRasterizeOptionsPi
+/// [program docs]: https://gdal.org/programs/gdal_rasterize.html
+pub fn rasterize(
+    src: &Dataset,
+    dst: &mut Dataset,
+    options: Option<RasterizeAppOptions>,
+) -> Result<()> {
+    let c_options = options
+        .as_ref()
+        .map(|x| x.c_options as *const GDALRasterizeOptions)
+        .unwrap_or(std::ptr::null());
+
+    let dataset_out = unsafe {
+        gdal_sys::CPLErrorReset();
+        gdal_sys::GDALRasterize(
+            std::ptr::null(),
+            dst.c_dataset(),
+            src.c_dataset(),
+            c_options,
+            null_mut(),
+        )
+    };
+
+    if dataset_out.is_null() {
+        return Err(_last_err_or_null_pointer("GDALRasterize"));
+    }
+
+    Ok(())
+}
+
+/// Burn the vector features of `src` into a new raster dataset at `dest`.
+/// Wraps [GDALRasterize].
+/// See the [program docs] for more details.
+///
+/// The output size must be given with `-ts` or `-tr`. The output format defaults
+/// to GeoTIFF; pass `-of MEM` and an empty `dest` to keep the result in memory.
+///
+/// [GDALRasterize]: https://gdal.org/api/gdal_utils.html#_CPPv413GDALRasterizePKc12GDALDatasetH12GDALDatasetHPK20GDALRasterizeOptionsPi
+/// [program docs]: https://gdal.org/programs/gdal_rasterize.html
+pub fn rasterize_create<P: AsRef<Path>>(
+    src: &Dataset,
+    dest: P,
+    options: Option<RasterizeAppOptions>,
+) -> Result<Dataset> {
+    _rasterize_create(src, dest.as_ref(), options)
+}
+
+fn _rasterize_create(
+    src: &Dataset,
+    dest: &Path,
+    options: Option<RasterizeAppOptions>,
+) -> Result<Dataset> {
+    let c_dest = _path_to_c_string(dest)?;
+
+    let c_options = options
+        .as_ref()
+        .map(|x| x.c_options as *const GDALRasterizeOptions)
+        .unwrap_or(std::ptr::null());
+
+    let dataset_out = unsafe {
+        gdal_sys::CPLErrorReset();
+        gdal_sys::GDALRasterize(
+            c_dest.as_ptr(),
+            null_mut(),
+            src.c_dataset(),
+            c_options,
+            null_mut(),
+        )
+    };
+
+    if dataset_out.is_null() {
+        return Err(_last_err_or_null_pointer("GDALRasterize"));
+    }
+
+    let result = unsafe { Dataset::from_c_dataset(dataset_out) };
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use gdal_sys::{OGRFieldType, OGRwkbGeometryType};
+
+    use super::*;
+
+    use crate::test_utils::{memory_dataset, memory_layer, SuppressGDALErrorLog};
+    use crate::vector::{Feature, Geometry, LayerAccess};
+    use crate::DriverManager;
+
+    /// A vector dataset with one polygon covering `(2, 2)` to `(6, 6)`, whose `value` is 7.
+    fn square_layer() -> Result<Dataset> {
+        let mut ds = memory_dataset()?;
+        {
+            let fields = [("value", OGRFieldType::OFTReal)];
+            let layer = memory_layer(&mut ds, "squares", OGRwkbGeometryType::wkbUnknown, &fields)?;
+            let mut feature = Feature::new(layer.defn())?;
+            feature.set_geometry(Geometry::bbox(2.0, 2.0, 6.0, 6.0)?)?;
+            feature.set_field_double(0, 7.0)?;
+            feature.create(&layer)?;
+        }
+        Ok(ds)
+    }
+
+    /// A blank 10x10 grid covering `(0, 0)` to `(10, 10)`.
+    fn blank_grid() -> Result<Dataset> {
+        let driver = DriverManager::get_driver_by_name("MEM")?;
+        let mut ds = driver.create("", 10, 10, 1)?;
+        ds.set_geo_transform(&[0.0, 1.0, 0.0, 10.0, 0.0, -1.0])?;
+        Ok(ds)
+    }
+
+    fn pixel(ds: &Dataset, x: isize, y: isize) -> u8 {
+        let band = ds.rasterband(1).unwrap();
+        band.read_as::<u8>((x, y), (1, 1), (1, 1), None)
+            .unwrap()
+            .data()[0]
+    }
+
+    #[test]
+    fn test_rasterize_burn() -> Result<()> {
+        let src = square_layer()?;
+        let mut dst = blank_grid()?;
+
+        let options = vec!["-burn", "5", "-l", "squares"].try_into()?;
+        rasterize(&src, &mut dst, Some(options))?;
+        // rows 4..8 and columns 2..6 are inside the square
+        assert_eq!(pixel(&dst, 3, 5), 5);
+        assert_eq!(pixel(&dst, 2, 4), 5);
+        assert_eq!(pixel(&dst, 0, 0), 0);
+        assert_eq!(pixel(&dst, 6, 5), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rasterize_attribute_where() -> Result<()> {
+        let src = square_layer()?;
+
+        let mut dst = blank_grid()?;
+        let options = RasterizeAppOptions::new(["-a", "value", "-where", "value > 5"])?;
+        rasterize(&src, &mut dst, Some(options))?;
+        assert_eq!(pixel(&dst, 3, 5), 7);
+
+        let mut dst = blank_grid()?;
+        let options = RasterizeAppOptions::new(["-a", "value", "-where", "value > 10"])?;
+        rasterize(&src, &mut dst, Some(options))?;
+        assert_eq!(pixel(&dst, 3, 5), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rasterize_create() -> Result<()> {
+        let src = square_layer()?;
+
+        let options = RasterizeAppOptions::new([
+            "-of", "MEM", "-burn", "1", "-ot", "Byte", "-te", "0", "0", "10", "10", "-ts", "20",
+            "20",
+        ])?;
+        let ds = rasterize_create(&src, "", Some(options))?;
+        assert_eq!(ds.raster_size(), (20, 20));
+        // the square covers columns 4..12 and rows 8..16
+        assert_eq!(pixel(&ds, 6, 10), 1);
+        assert_eq!(pixel(&ds, 1, 1), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rasterize_options_invalid() {
+        let _nolog = SuppressGDALErrorLog::new();
+        assert!(RasterizeAppOptions::new(["-burn"]).is_err());
+    }
+}
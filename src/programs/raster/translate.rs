@@ -0,0 +1,190 @@
+use std::{path::Path, ptr::null_mut};
+
+use gdal_sys::GDALTranslateOptions;
+
+use crate::{
+    errors::*,
+    programs::{new_options, ProgressCallback},
+    utils::{_last_err_or_null_pointer, _path_to_c_string},
+    Dataset,
+};
+
+/// Wraps a [GDALTranslateOptions] object.
+///
+/// [GDALTranslateOptions]: https://gdal.org/api/gdal_utils.html#_CPPv420GDALTranslateOptions
+pub struct TranslateOptions {
+    c_options: *mut GDALTranslateOptions,
+    progress: Option<ProgressCallback>,
+}
+
+impl TranslateOptions {
+    /// See [GDALTranslateOptionsNew].
+    ///
+    /// [GDALTranslateOptionsNew]: https://gdal.org/api/gdal_utils.html#_CPPv423GDALTranslateOptionsNewPPcP29GDALTranslateOptionsForBinary
+    ///
+    /// `args` are the command line arguments of [`gdal_translate`], e.g.
+    /// `["-of", "COG", "-projwin", "0", "10", "10", "0", "-b", "1"]`.
+    /// Unknown flags, and flags missing their values, are rejected here.
+    ///
+    /// [`gdal_translate`]: https://gdal.org/programs/gdal_translate.html
+    pub fn new<S: Into<Vec<u8>>, I: IntoIterator<Item = S>>(args: I) -> Result<Self> {
+        let c_options = new_options(args, "GDALTranslateOptionsNew", |c_args| unsafe {
+            gdal_sys::GDALTranslateOptionsNew(c_args, null_mut())
+        })?;
+        Ok(Self {
+            c_options,
+            progress: None,
+        })
+    }
+
+    /// Report progress of the translation to `callback`.
+    ///
+    /// The callback receives the completed fraction, between `0.0` and `1.0`, and
+    /// a possibly empty status message. Returning `false` cancels the translation,
+    /// in which case [`translate`] returns an error.
+    ///
+    /// See [GDALTranslateOptionsSetProgress].
+    ///
+    /// [GDALTranslateOptionsSetProgress]: https://gdal.org/api/gdal_utils.html#_CPPv431GDALTranslateOptionsSetProgressP20GDALTranslateOptions16GDALProgressFuncPv
+    pub fn set_progress<F: FnMut(f64, &str) -> bool + 'static>(&mut self, callback: F) {
+        let progress = unsafe {
+            ProgressCallback::register(
+                self.c_options,
+                gdal_sys::GDALTranslateOptionsSetProgress,
+                callback,
+            )
+        };
+        self.progress = Some(progress);
+    }
+
+    /// Returns the wrapped C pointer
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_options(&self) -> *mut GDALTranslateOptions {
+        self.c_options
+    }
+}
+
+impl Drop for TranslateOptions {
+    fn drop(&mut self) {
+        unsafe {
+            gdal_sys::GDALTranslateOptionsFree(self.c_options);
+        }
+    }
+}
+
+impl TryFrom<Vec<&str>> for TranslateOptions {
+    type Error = GdalError;
+
+    fn try_from(value: Vec<&str>) -> Result<Self> {
+        TranslateOptions::new(value)
+    }
+}
+
+/// Convert `src` into a new dataset at `dest`, optionally subsetting, resampling
+/// or changing its format.
+/// Wraps [GDALTranslate].
+/// See the [program docs] for more details.
+///
+/// Without `-of`, the format is guessed from the extension of `dest`, falling back
+/// to GeoTIFF.
+///
+/// [GDALTranslate]: https://gdal.org/api/gdal_utils.html#_CPPv413GDALTranslatePKc12GDALDatasetHPK20GDALTranslateOptionsPi
+/// [program docs]: https://gdal.org/programs/gdal_translate.html
+pub fn translate<P: AsRef<Path>>(
+    src: &Dataset,
+    dest: P,
+    options: Option<TranslateOptions>,
+) -> Result<Dataset> {
+    _translate(src, dest.as_ref(), options)
+}
+
+fn _translate(src: &Dataset, dest: &Path, options: Option<TranslateOptions>) -> Result<Dataset> {
+    let c_dest = _path_to_c_string(dest)?;
+
+    let c_options = options
+        .as_ref()
+        .map(|x| x.c_options as *const GDALTranslateOptions)
+        .unwrap_or(std::ptr::null());
+
+    let dataset_out = unsafe {
+        gdal_sys::CPLErrorReset();
+        gdal_sys::GDALTranslate(c_dest.as_ptr(), src.c_dataset(), c_options, null_mut())
+    };
+
+    if dataset_out.is_null() {
+        return Err(_last_err_or_null_pointer("GDALTranslate"));
+    }
+
+    let result = unsafe { Dataset::from_c_dataset(dataset_out) };
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+
+    use crate::test_utils::{fixture, InMemoryFixture, SuppressGDALErrorLog};
+
+    #[test]
+    fn test_translate_srcwin() {
+        let src = Dataset::open(fixture("tinymarble.tif")).unwrap();
+        let output = InMemoryFixture::new("translate_srcwin.tif");
+
+        let options = vec!["-srcwin", "10", "5", "20", "15"].try_into().unwrap();
+        let ds = translate(&src, output.path(), Some(options)).unwrap();
+        assert_eq!(ds.raster_size(), (20, 15));
+        assert_eq!(ds.raster_count(), src.raster_count());
+        assert_eq!(ds.driver().short_name(), "GTiff");
+    }
+
+    #[test]
+    fn test_translate_outsize_bands() {
+        let src = Dataset::open(fixture("tinymarble.tif")).unwrap();
+
+        let options =
+            TranslateOptions::new(["-of", "MEM", "-outsize", "50%", "50%", "-b", "1"]).unwrap();
+        let ds = translate(&src, "", Some(options)).unwrap();
+        assert_eq!(ds.raster_size(), (50, 25));
+        assert_eq!(ds.raster_count(), 1);
+        assert_eq!(ds.driver().short_name(), "MEM");
+    }
+
+    #[test]
+    fn test_translate_progress() {
+        let src = Dataset::open(fixture("tinymarble.tif")).unwrap();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        let mut options = TranslateOptions::new(["-of", "MEM"]).unwrap();
+        let sink = seen.clone();
+        options.set_progress(move |complete, _| {
+            sink.borrow_mut().push(complete);
+            true
+        });
+        translate(&src, "", Some(options)).unwrap();
+
+        let seen = seen.borrow();
+        assert!(!seen.is_empty());
+        assert!(seen.iter().all(|c| (0.0..=1.0).contains(c)));
+    }
+
+    #[test]
+    fn test_translate_progress_cancel() {
+        let _nolog = SuppressGDALErrorLog::new();
+        let src = Dataset::open(fixture("tinymarble.tif")).unwrap();
+
+        let mut options = TranslateOptions::new(["-of", "MEM"]).unwrap();
+        options.set_progress(|_, _| false);
+        assert!(translate(&src, "", Some(options)).is_err());
+    }
+
+    #[test]
+    fn test_translate_options_invalid() {
+        let _nolog = SuppressGDALErrorLog::new();
+        assert!(TranslateOptions::new(["-outsize", "10"]).is_err());
+    }
+}
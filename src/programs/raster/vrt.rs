@@ -1,6 +1,6 @@
 use std::{
     borrow::Borrow,
-    ffi::{c_char, c_int, CString},
+    ffi::c_int,
     path::Path,
     ptr::{null, null_mut},
 };
@@ -9,7 +9,8 @@ use gdal_sys::GDALBuildVRTOptions;
 
 use crate::{
     errors::*,
-    utils::{_last_null_pointer_err, _path_to_c_string},
+    programs::{new_options, ProgressCallback},
+    utils::{_last_err_or_null_pointer, _path_to_c_string},
     Dataset,
 };
 
@@ -18,33 +19,46 @@ use crate::{
 /// [GDALBuildVRTOptions]: https://gdal.org/api/gdal_utils.html#_CPPv419GDALBuildVRTOptions
 pub struct BuildVRTOptions {
     c_options: *mut GDALBuildVRTOptions,
+    progress: Option<ProgressCallback>,
 }
 
 impl BuildVRTOptions {
     /// See [GDALBuildVRTOptionsNew].
     ///
     /// [GDALBuildVRTOptionsNew]: https://gdal.org/api/gdal_utils.html#_CPPv422GDALBuildVRTOptionsNewPPcP28GDALBuildVRTOptionsForBinary
+    ///
+    /// `args` are the command line arguments of [`gdalbuildvrt`], e.g.
+    /// `["-separate", "-resolution", "highest", "-te", "0", "0", "10", "10", "-r", "bilinear"]`.
+    ///
+    /// [`gdalbuildvrt`]: https://gdal.org/programs/gdalbuildvrt.html
     pub fn new<S: Into<Vec<u8>>, I: IntoIterator<Item = S>>(args: I) -> Result<Self> {
-        // Convert args to CStrings to add terminating null bytes
-        let cstr_args = args
-            .into_iter()
-            .map(CString::new)
-            .collect::<std::result::Result<Vec<_>, _>>()?;
-
-        // Get pointers to the strings
-        // These strings don't actually get modified, the C API is just not const-correct
-        // Null-terminate the list
-        let mut c_args = cstr_args
-            .iter()
-            .map(|x| x.as_ptr() as *mut c_char)
-            .chain(std::iter::once(null_mut()))
-            .collect::<Vec<_>>();
+        let c_options = new_options(args, "GDALBuildVRTOptionsNew", |c_args| unsafe {
+            gdal_sys::GDALBuildVRTOptionsNew(c_args, null_mut())
+        })?;
+        Ok(Self {
+            c_options,
+            progress: None,
+        })
+    }
 
-        unsafe {
-            Ok(Self {
-                c_options: gdal_sys::GDALBuildVRTOptionsNew(c_args.as_mut_ptr(), null_mut()),
-            })
-        }
+    /// Report progress of the VRT construction to `callback`.
+    ///
+    /// The callback receives the completed fraction, between `0.0` and `1.0`, and
+    /// a possibly empty status message. Returning `false` cancels the build, in
+    /// which case [`build_vrt`] returns an error.
+    ///
+    /// See [GDALBuildVRTOptionsSetProgress].
+    ///
+    /// [GDALBuildVRTOptionsSetProgress]: https://gdal.org/api/gdal_utils.html#_CPPv430GDALBuildVRTOptionsSetProgressP19GDALBuildVRTOptions16GDALProgressFuncPv
+    pub fn set_progress<F: FnMut(f64, &str) -> bool + 'static>(&mut self, callback: F) {
+        let progress = unsafe {
+            ProgressCallback::register(
+                self.c_options,
+                gdal_sys::GDALBuildVRTOptionsSetProgress,
+                callback,
+            )
+        };
+        self.progress = Some(progress);
     }
 
     /// Returns the wrapped C pointer
@@ -64,6 +78,14 @@ impl Drop for BuildVRTOptions {
     }
 }
 
+impl TryFrom<Vec<&str>> for BuildVRTOptions {
+    type Error = GdalError;
+
+    fn try_from(value: Vec<&str>) -> Result<Self> {
+        BuildVRTOptions::new(value)
+    }
+}
+
 /// Build a VRT from a list of datasets.
 /// Wraps [GDALBuildVRT].
 /// See the [program docs] for more details.
@@ -85,6 +107,59 @@ pub fn build_vrt<D: Borrow<Dataset>>(
     )
 }
 
+/// Build a VRT from a list of dataset paths, which GDAL opens itself.
+/// Wraps [GDALBuildVRT].
+/// See the [program docs] for more details.
+///
+/// This avoids keeping every source open at once, which matters for large tile
+/// sets.
+///
+/// [GDALBuildVRT]: https://gdal.org/api/gdal_utils.html#gdal__utils_8h_1a057aaea8b0ed0476809a781ffa377ea4
+/// [program docs]: https://gdal.org/programs/gdalbuildvrt.html
+pub fn build_vrt_from_paths<P: AsRef<Path>>(
+    dest: Option<&Path>,
+    paths: &[P],
+    options: Option<BuildVRTOptions>,
+) -> Result<Dataset> {
+    let dest = dest.map(_path_to_c_string).transpose()?;
+    let c_dest = dest.as_ref().map(|x| x.as_ptr()).unwrap_or(null());
+
+    let c_paths = paths
+        .iter()
+        .map(|p| _path_to_c_string(p.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+    let c_path_ptrs = c_paths
+        .iter()
+        .map(|x| x.as_ptr())
+        .chain(std::iter::once(null()))
+        .collect::<Vec<_>>();
+
+    let c_options = options
+        .as_ref()
+        .map(|x| x.c_options as *const GDALBuildVRTOptions)
+        .unwrap_or(null());
+
+    let dataset_out = unsafe {
+        gdal_sys::CPLErrorReset();
+        gdal_sys::GDALBuildVRT(
+            c_dest,
+            c_paths.len() as c_int,
+            null_mut(),
+            c_path_ptrs.as_ptr(),
+            c_options,
+            null_mut(),
+        )
+    };
+
+    if dataset_out.is_null() {
+        return Err(_last_err_or_null_pointer("GDALBuildVRT"));
+    }
+
+    let result = unsafe { Dataset::from_c_dataset(dataset_out) };
+
+    Ok(result)
+}
+
 fn _build_vrt(
     dest: Option<&Path>,
     datasets: &[&Dataset],
@@ -104,6 +179,7 @@ fn _build_vrt(
         let mut datasets_raw: Vec<gdal_sys::GDALDatasetH> =
             datasets.iter().map(|x| x.c_dataset()).collect();
 
+        gdal_sys::CPLErrorReset();
         gdal_sys::GDALBuildVRT(
             c_dest,
             datasets_raw.len() as c_int,
@@ -115,10 +191,120 @@ fn _build_vrt(
     };
 
     if dataset_out.is_null() {
-        return Err(_last_null_pointer_err("GDALBuildVRT"));
+        return Err(_last_err_or_null_pointer("GDALBuildVRT"));
     }
 
     let result = unsafe { Dataset::from_c_dataset(dataset_out) };
 
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+
+    use crate::test_utils::{geo_tile, InMemoryFixture, SuppressGDALErrorLog};
+    use crate::{GeoTransformEx, Metadata};
+
+    /// Creates two adjacent GeoTIFF tiles in `/vsimem/`, at `x = 0` and `x = 10`.
+    ///
+    /// The files are removed when the returned fixtures are dropped.
+    fn tiles(name: &str) -> ([InMemoryFixture; 2], [Dataset; 2]) {
+        let fixtures = [
+            InMemoryFixture::new(&format!("build_vrt/{name}_a.tif")),
+            InMemoryFixture::new(&format!("build_vrt/{name}_b.tif")),
+        ];
+        let tiles = [
+            geo_tile(fixtures[0].path().to_str().unwrap(), 0.0),
+            geo_tile(fixtures[1].path().to_str().unwrap(), 10.0),
+        ];
+        (fixtures, tiles)
+    }
+
+    #[test]
+    fn test_build_vrt_mosaic() {
+        let (_fixtures, tiles) = tiles("mosaic");
+
+        let vrt = build_vrt(None, &tiles, None).unwrap();
+        assert_eq!(vrt.raster_size(), (20, 10));
+        assert_eq!(vrt.raster_count(), 1);
+        let gt = vrt.geo_transform().unwrap();
+        assert_eq!(gt.apply(0.0, 0.0), (0.0, 10.0));
+        assert_eq!(gt.apply(20.0, 10.0), (20.0, 0.0));
+    }
+
+    #[test]
+    fn test_build_vrt_separate() {
+        let (_fixtures, tiles) = tiles("separate");
+
+        let options = vec!["-separate", "-te", "0", "0", "10", "10", "-r", "nearest"]
+            .try_into()
+            .unwrap();
+        let vrt = build_vrt(None, &tiles, Some(options)).unwrap();
+        assert_eq!(vrt.raster_size(), (10, 10));
+        assert_eq!(vrt.raster_count(), 2);
+    }
+
+    #[test]
+    fn test_build_vrt_resolution() {
+        let (_fixtures, mut tiles) = tiles("resolution");
+        // Coarsen the second tile to 2x2 pixels, covering x from 10 to 30 and y from -10 to 10
+        tiles[1]
+            .set_geo_transform(&[10.0, 2.0, 0.0, 10.0, 0.0, -2.0])
+            .unwrap();
+
+        let options = BuildVRTOptions::new(["-resolution", "highest"]).unwrap();
+        let vrt = build_vrt(None, &tiles, Some(options)).unwrap();
+        assert_eq!(vrt.raster_size(), (30, 20));
+
+        let options = BuildVRTOptions::new(["-resolution", "lowest"]).unwrap();
+        let vrt = build_vrt(None, &tiles, Some(options)).unwrap();
+        assert_eq!(vrt.raster_size(), (15, 10));
+    }
+
+    #[test]
+    fn test_build_vrt_target_resolution() {
+        let (_fixtures, tiles) = tiles("target_resolution");
+
+        let options = BuildVRTOptions::new(["-tr", "2", "2"]).unwrap();
+        let vrt = build_vrt(None, &tiles, Some(options)).unwrap();
+        assert_eq!(vrt.raster_size(), (10, 5));
+    }
+
+    #[test]
+    fn test_build_vrt_from_paths() {
+        let (_fixtures, tiles) = tiles("paths");
+        let paths = tiles
+            .iter()
+            .map(|t| t.description().unwrap())
+            .collect::<Vec<_>>();
+
+        let vrt = build_vrt_from_paths(None, &paths, None).unwrap();
+        assert_eq!(vrt.raster_size(), (20, 10));
+        assert_eq!(vrt.geo_transform().unwrap().apply(20.0, 10.0), (20.0, 0.0));
+    }
+
+    #[test]
+    fn test_build_vrt_addalpha_progress() {
+        let (_fixtures, tiles) = tiles("alpha");
+        let calls = Rc::new(Cell::new(0));
+
+        let mut options = BuildVRTOptions::new(["-addalpha", "-resolution", "highest"]).unwrap();
+        let counter = calls.clone();
+        options.set_progress(move |_, _| {
+            counter.set(counter.get() + 1);
+            true
+        });
+        let vrt = build_vrt(None, &tiles, Some(options)).unwrap();
+        assert_eq!(vrt.raster_count(), 2);
+        assert!(calls.get() > 0);
+    }
+
+    #[test]
+    fn test_build_vrt_options_invalid() {
+        let _nolog = SuppressGDALErrorLog::new();
+        assert!(BuildVRTOptions::new(["-resolution", "bogus"]).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+use std::{
+    borrow::Borrow,
+    ffi::c_int,
+    path::Path,
+    ptr::{null, null_mut},
+};
+
+use gdal_sys::GDALWarpAppOptions;
+
+use crate::{
+    errors::*,
+    programs::{new_options, ProgressCallback},
+    utils::{_last_err_or_null_pointer, _path_to_c_string},
+    Dataset,
+};
+
+/// Wraps a [GDALWarpAppOptions] object.
+///
+/// [GDALWarpAppOptions]: https://gdal.org/api/gdal_utils.html#_CPPv418GDALWarpAppOptions
+pub struct WarpAppOptions {
+    c_options: *mut GDALWarpAppOptions,
+    progress: Option<ProgressCallback>,
+}
+
+impl WarpAppOptions {
+    /// See [GDALWarpAppOptionsNew].
+    ///
+    /// [GDALWarpAppOptionsNew]: https://gdal.org/api/gdal_utils.html#_CPPv421GDALWarpAppOptionsNewPPcP27GDALWarpAppOptionsForBinary
+    ///
+    /// `args` are the command line arguments of [`gdalwarp`], e.g.
+    /// `["-t_srs", "EPSG:3857", "-r", "bilinear", "-tr", "100", "100"]`.
+    /// An unknown `-r` resampling method is rejected here rather than when warping.
+    ///
+    /// [`gdalwarp`]: https://gdal.org/programs/gdalwarp.html
+    pub fn new<S: Into<Vec<u8>>, I: IntoIterator<Item = S>>(args: I) -> Result<Self> {
+        let c_options = new_options(args, "GDALWarpAppOptionsNew", |c_args| unsafe {
+            gdal_sys::GDALWarpAppOptionsNew(c_args, null_mut())
+        })?;
+        Ok(Self {
+            c_options,
+            progress: None,
+        })
+    }
+
+    /// Report progress of the warp to `callback`.
+    ///
+    /// The callback receives the completed fraction, between `0.0` and `1.0`, and
+    /// a possibly empty status message. Returning `false` cancels the warp, in
+    /// which case [`warp`] returns an error.
+    ///
+    /// See [GDALWarpAppOptionsSetProgress].
+    ///
+    /// [GDALWarpAppOptionsSetProgress]: https://gdal.org/api/gdal_utils.html#_CPPv429GDALWarpAppOptionsSetProgressP18GDALWarpAppOptions16GDALProgressFuncPv
+    pub fn set_progress<F: FnMut(f64, &str) -> bool + 'static>(&mut self, callback: F) {
+        let progress = unsafe {
+            ProgressCallback::register(
+                self.c_options,
+                gdal_sys::GDALWarpAppOptionsSetProgress,
+                callback,
+            )
+        };
+        self.progress = Some(progress);
+    }
+
+    /// Returns the wrapped C pointer
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_options(&self) -> *mut GDALWarpAppOptions {
+        self.c_options
+    }
+}
+
+impl Drop for WarpAppOptions {
+    fn drop(&mut self) {
+        unsafe {
+            gdal_sys::GDALWarpAppOptionsFree(self.c_options);
+        }
+    }
+}
+
+impl TryFrom<Vec<&str>> for WarpAppOptions {
+    type Error = GdalError;
+
+    fn try_from(value: Vec<&str>) -> Result<Self> {
+        WarpAppOptions::new(value)
+    }
+}
+
+/// Reproject and/or mosaic `srcs` into a new dataset at `dest`.
+/// Wraps [GDALWarp].
+/// See the [program docs] for more details.
+///
+/// To warp into memory, pass `-of MEM` and an empty `dest`.
+///
+/// [GDALWarp]: https://gdal.org/api/gdal_utils.html#_CPPv48GDALWarpPKc12GDALDatasetHiP12GDALDatasetHPK18GDALWarpAppOptionsPi
+/// [program docs]: https://gdal.org/programs/gdalwarp.html
+pub fn warp<D: Borrow<Dataset>, P: AsRef<Path>>(
+    srcs: &[D],
+    dest: P,
+    options: Option<WarpAppOptions>,
+) -> Result<Dataset> {
+    _warp(
+        &srcs.iter().map(|x| x.borrow()).collect::<Vec<&Dataset>>(),
+        dest.as_ref(),
+        options,
+    )
+}
+
+fn _warp(srcs: &[&Dataset], dest: &Path, options: Option<WarpAppOptions>) -> Result<Dataset> {
+    let c_dest = _path_to_c_string(dest)?;
+
+    let c_options = options
+        .as_ref()
+        .map(|x| x.c_options as *const GDALWarpAppOptions)
+        .unwrap_or(null());
+
+    let dataset_out = unsafe {
+        // Get raw handles to the datasets
+        let mut srcs_raw: Vec<gdal_sys::GDALDatasetH> =
+            srcs.iter().map(|x| x.c_dataset()).collect();
+
+        gdal_sys::CPLErrorReset();
+        gdal_sys::GDALWarp(
+            c_dest.as_ptr(),
+            null_mut(),
+            srcs_raw.len() as c_int,
+            srcs_raw.as_mut_ptr(),
+            c_options,
+            null_mut(),
+        )
+    };
+
+    if dataset_out.is_null() {
+        return Err(_last_err_or_null_pointer("GDALWarp"));
+    }
+
+    let result = unsafe { Dataset::from_c_dataset(dataset_out) };
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+
+    use crate::test_utils::{geo_tile, SuppressGDALErrorLog};
+    use crate::GeoTransformEx;
+
+    #[test]
+    fn test_warp_reproject() {
+        let src = geo_tile("", 0.0);
+
+        let options = vec!["-of", "MEM", "-t_srs", "EPSG:3857", "-r", "bilinear"]
+            .try_into()
+            .unwrap();
+        let ds = warp(&[src], "", Some(options)).unwrap();
+        let srs = ds.spatial_ref().unwrap();
+        assert_eq!(srs.auth_code().unwrap(), 3857);
+        let gt = ds.geo_transform().unwrap();
+        assert!(gt[0].abs() < 1e-6);
+        assert!(gt.apply(0.0, 0.0).1 > 1_000_000.0);
+    }
+
+    #[test]
+    fn test_warp_mosaic() {
+        let tiles = [geo_tile("", 0.0), geo_tile("", 10.0)];
+
+        let options =
+            WarpAppOptions::new(["-of", "MEM", "-te", "0", "0", "20", "10", "-tr", "2", "2"])
+                .unwrap();
+        let ds = warp(&tiles, "", Some(options)).unwrap();
+        assert_eq!(ds.raster_size(), (10, 5));
+        assert_eq!(ds.geo_transform().unwrap().apply(10.0, 5.0), (20.0, 0.0));
+    }
+
+    #[test]
+    fn test_warp_progress() {
+        let src = geo_tile("", 0.0);
+        let calls = Rc::new(Cell::new(0));
+
+        let mut options = WarpAppOptions::new(["-of", "MEM", "-t_srs", "EPSG:3857"]).unwrap();
+        let counter = calls.clone();
+        options.set_progress(move |complete, _| {
+            counter.set(counter.get() + 1);
+            (0.0..=1.0).contains(&complete)
+        });
+        warp(&[src], "", Some(options)).unwrap();
+        assert!(calls.get() > 0);
+    }
+
+    #[test]
+    fn test_warp_options_invalid() {
+        let _nolog = SuppressGDALErrorLog::new();
+        assert!(WarpAppOptions::new(["-r", "bogus"]).is_err());
+    }
+}
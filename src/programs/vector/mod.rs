@@ -0,0 +1,3 @@
+mod vector_translate;
+
+pub use vector_translate::*;
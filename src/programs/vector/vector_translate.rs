@@ -0,0 +1,192 @@
+use std::{
+    borrow::Borrow,
+    ffi::c_int,
+    path::Path,
+    ptr::{null, null_mut},
+};
+
+use gdal_sys::GDALVectorTranslateOptions;
+
+use crate::{
+    errors::*,
+    programs::{new_options, ProgressCallback},
+    utils::{_last_err_or_null_pointer, _path_to_c_string},
+    Dataset,
+};
+
+/// Wraps a [GDALVectorTranslateOptions] object.
+///
+/// [GDALVectorTranslateOptions]: https://gdal.org/api/gdal_utils.html#_CPPv426GDALVectorTranslateOptions
+pub struct VectorTranslateOptions {
+    c_options: *mut GDALVectorTranslateOptions,
+    progress: Option<ProgressCallback>,
+}
+
+impl VectorTranslateOptions {
+    /// See [GDALVectorTranslateOptionsNew].
+    ///
+    /// [GDALVectorTranslateOptionsNew]: https://gdal.org/api/gdal_utils.html#_CPPv429GDALVectorTranslateOptionsNewPPcP35GDALVectorTranslateOptionsForBinary
+    ///
+    /// `args` are the command line arguments of [`ogr2ogr`], e.g.
+    /// `["-f", "GPKG", "-t_srs", "EPSG:3857", "-where", "pop > 1000", "-nln", "cities"]`.
+    /// Without `-f`, the output driver is guessed from the extension of the destination.
+    ///
+    /// [`ogr2ogr`]: https://gdal.org/programs/ogr2ogr.html
+    pub fn new<S: Into<Vec<u8>>, I: IntoIterator<Item = S>>(args: I) -> Result<Self> {
+        let c_options = new_options(args, "GDALVectorTranslateOptionsNew", |c_args| unsafe {
+            gdal_sys::GDALVectorTranslateOptionsNew(c_args, null_mut())
+        })?;
+        Ok(Self {
+            c_options,
+            progress: None,
+        })
+    }
+
+    /// Report progress of the translation to `callback`.
+    ///
+    /// The callback receives the completed fraction, between `0.0` and `1.0`, and
+    /// a possibly empty status message. Returning `false` cancels the translation,
+    /// in which case [`vector_translate`] returns an error.
+    ///
+    /// See [GDALVectorTranslateOptionsSetProgress].
+    ///
+    /// [GDALVectorTranslateOptionsSetProgress]: https://gdal.org/api/gdal_utils.html#_CPPv437GDALVectorTranslateOptionsSetProgressP26GDALVectorTranslateOptions16GDALProgressFuncPv
+    pub fn set_progress<F: FnMut(f64, &str) -> bool + 'static>(&mut self, callback: F) {
+        let progress = unsafe {
+            ProgressCallback::register(
+                self.c_options,
+                gdal_sys::GDALVectorTranslateOptionsSetProgress,
+                callback,
+            )
+        };
+        self.progress = Some(progress);
+    }
+
+    /// Returns the wrapped C pointer
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_options(&self) -> *mut GDALVectorTranslateOptions {
+        self.c_options
+    }
+}
+
+impl Drop for VectorTranslateOptions {
+    fn drop(&mut self) {
+        unsafe {
+            gdal_sys::GDALVectorTranslateOptionsFree(self.c_options);
+        }
+    }
+}
+
+impl TryFrom<Vec<&str>> for VectorTranslateOptions {
+    type Error = GdalError;
+
+    fn try_from(value: Vec<&str>) -> Result<Self> {
+        VectorTranslateOptions::new(value)
+    }
+}
+
+/// Convert, filter and/or reproject the layers of `srcs` into a new dataset at `dest`.
+/// Wraps [GDALVectorTranslate].
+/// See the [program docs] for more details.
+///
+/// The output format defaults to GeoPackage on recent GDAL versions; pass `-f` to
+/// choose another one.
+///
+/// [GDALVectorTranslate]: https://gdal.org/api/gdal_utils.html#_CPPv419GDALVectorTranslatePKc12GDALDatasetHiP12GDALDatasetHPK26GDALVectorTranslateOptionsPi
+/// [program docs]: https://gdal.org/programs/ogr2ogr.html
+pub fn vector_translate<D: Borrow<Dataset>, P: AsRef<Path>>(
+    srcs: &[D],
+    dest: P,
+    options: Option<VectorTranslateOptions>,
+) -> Result<Dataset> {
+    _vector_translate(
+        &srcs.iter().map(|x| x.borrow()).collect::<Vec<&Dataset>>(),
+        dest.as_ref(),
+        options,
+    )
+}
+
+fn _vector_translate(
+    srcs: &[&Dataset],
+    dest: &Path,
+    options: Option<VectorTranslateOptions>,
+) -> Result<Dataset> {
+    let c_dest = _path_to_c_string(dest)?;
+
+    let c_options = options
+        .as_ref()
+        .map(|x| x.c_options as *const GDALVectorTranslateOptions)
+        .unwrap_or(null());
+
+    let dataset_out = unsafe {
+        // Get raw handles to the datasets
+        let mut srcs_raw: Vec<gdal_sys::GDALDatasetH> =
+            srcs.iter().map(|x| x.c_dataset()).collect();
+
+        gdal_sys::CPLErrorReset();
+        gdal_sys::GDALVectorTranslate(
+            c_dest.as_ptr(),
+            null_mut(),
+            srcs_raw.len() as c_int,
+            srcs_raw.as_mut_ptr(),
+            c_options,
+            null_mut(),
+        )
+    };
+
+    if dataset_out.is_null() {
+        return Err(_last_err_or_null_pointer("GDALVectorTranslate"));
+    }
+
+    let result = unsafe { Dataset::from_c_dataset(dataset_out) };
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_utils::{fixture, InMemoryFixture, SuppressGDALErrorLog};
+    use crate::vector::LayerAccess;
+
+    #[test]
+    fn test_vector_translate_to_gpkg() {
+        let src = Dataset::open(fixture("roads.geojson")).unwrap();
+        let output = InMemoryFixture::new("vector_translate.gpkg");
+
+        let options = vec!["-f", "GPKG", "-nln", "streets"].try_into().unwrap();
+        let ds = vector_translate(&[src], output.path(), Some(options)).unwrap();
+        assert_eq!(ds.driver().short_name(), "GPKG");
+        assert_eq!(ds.layer_count(), 1);
+        let layer = ds.layer_by_name("streets").unwrap();
+        assert_eq!(layer.feature_count(), 21);
+    }
+
+    #[test]
+    fn test_vector_translate_where_reproject() {
+        let src = Dataset::open(fixture("roads.geojson")).unwrap();
+
+        let options = VectorTranslateOptions::new([
+            "-f",
+            "Memory",
+            "-where",
+            "highway = 'residential'",
+            "-t_srs",
+            "EPSG:3857",
+        ])
+        .unwrap();
+        let ds = vector_translate(&[src], "", Some(options)).unwrap();
+        let layer = ds.layer(0).unwrap();
+        assert_eq!(layer.feature_count(), 2);
+        assert_eq!(layer.spatial_ref().unwrap().auth_code().unwrap(), 3857);
+    }
+
+    #[test]
+    fn test_vector_translate_options_invalid() {
+        let _nolog = SuppressGDALErrorLog::new();
+        assert!(VectorTranslateOptions::new(["-f"]).is_err());
+    }
+}
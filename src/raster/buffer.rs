@@ -1,3 +1,4 @@
+use crate::errors::{GdalError, Result};
 use crate::raster::GdalType;
 use std::ops::{Index, IndexMut};
 use std::slice::{Iter, IterMut};
@@ -86,7 +87,25 @@ impl<T: GdalType> Buffer<T> {
         Buffer { shape, data }
     }
 
+    /// Construct a new buffer from `shape` (`(cols, rows)`) and `Vec<T>`, like
+    /// [`Buffer::new`], but without panicking.
+    ///
+    /// Returns [`GdalError::BadArgument`] if `shape.0 * shape.1 != data.len()`.
+    pub fn from_vec(shape: (usize, usize), data: Vec<T>) -> Result<Self> {
+        if shape.0.checked_mul(shape.1) != Some(data.len()) {
+            return Err(GdalError::BadArgument(format!(
+                "shape {}*{} does not match length {}",
+                shape.0,
+                shape.1,
+                data.len()
+            )));
+        }
+        Ok(Buffer { shape, data })
+    }
+
     /// Destructures `self` into constituent parts.
+    ///
+    /// The inverse of [`Buffer::from_vec`].
     pub fn into_shape_and_vec(self) -> ((usize, usize), Vec<T>) {
         (self.shape, self.data)
     }
@@ -123,6 +142,16 @@ impl<T: GdalType> Buffer<T> {
         self.data.as_mut_slice()
     }
 
+    /// Same as [`data`][Self::data].
+    pub fn as_slice(&self) -> &[T] {
+        self.data()
+    }
+
+    /// Same as [`data_mut`][Self::data_mut].
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data_mut()
+    }
+
     /// Get the number of elements in the buffer
     pub fn len(&self) -> usize {
         self.data.len()
@@ -135,7 +164,7 @@ impl<T: GdalType> Buffer<T> {
 
     #[cfg(feature = "ndarray")]
     /// Convert `self` into an [`ndarray::Array2<T>`].
-    pub fn to_array(self) -> crate::errors::Result<Array2<T>> {
+    pub fn to_array(self) -> Result<Array2<T>> {
         // Array2 shape is (rows, cols) and Buffer shape is (cols in x-axis, rows in y-axis)
         Ok(Array2::from_shape_vec(
             (self.shape.1, self.shape.0),
@@ -205,9 +234,9 @@ pub type ByteBuffer = Buffer<u8>;
 
 #[cfg(feature = "ndarray")]
 impl<T: GdalType> TryFrom<Buffer<T>> for Array2<T> {
-    type Error = crate::errors::GdalError;
+    type Error = GdalError;
 
-    fn try_from(value: Buffer<T>) -> Result<Self, Self::Error> {
+    fn try_from(value: Buffer<T>) -> Result<Self> {
         value.to_array()
     }
 }
@@ -232,13 +261,15 @@ impl<T: GdalType + Copy> From<Array2<T>> for Buffer<T> {
     }
 }
 
-#[cfg(feature = "ndarray")]
 #[cfg(test)]
 mod tests {
+    use crate::errors::GdalError;
     use crate::raster::Buffer;
+    #[cfg(feature = "ndarray")]
     use ndarray::{arr2, s, Array2, ShapeBuilder};
 
     #[test]
+    #[cfg(feature = "ndarray")]
     fn convert_to() {
         let b = Buffer::new((5, 10), (0..5 * 10).collect());
         let a = b.clone().to_array().unwrap();
@@ -249,6 +280,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "ndarray")]
     fn convert_from() {
         let a = Array2::from_shape_fn((10, 5), |(y, x)| y as i32 * 5 + x as i32);
         let b: Buffer<_> = a.clone().into();
@@ -260,6 +292,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "ndarray")]
     fn shapes() {
         let s1 = (10, 5).set_f(true);
         let s2 = (10, 5).set_f(false);
@@ -279,6 +312,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "ndarray")]
     fn offset() {
         let arr = arr2(&[[0, 1, 2], [10, 11, 12]]);
         let slice = arr.slice_move(s![1.., ..]);
@@ -346,6 +380,33 @@ mod tests {
         assert_eq!(99, b[(0, 1)]);
     }
 
+    #[test]
+    fn vec_round_trip() {
+        let b = Buffer::from_vec((3, 2), vec![1u8, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(b[(1, 0)], 4);
+
+        // run a kernel over the pixels with plain slice code
+        let mut b = b;
+        for v in b.as_mut_slice() {
+            *v *= 10;
+        }
+        assert_eq!(b.as_slice(), b.data());
+        let (shape, data) = b.into_shape_and_vec();
+        assert_eq!(shape, (3, 2));
+        assert_eq!(data, [10, 20, 30, 40, 50, 60]);
+
+        let b = Buffer::from_vec(shape, data).unwrap();
+        assert_eq!(b.data(), [10, 20, 30, 40, 50, 60]);
+    }
+
+    #[test]
+    fn from_vec_bad_shape() {
+        let res = Buffer::from_vec((3, 3), vec![0u8; 8]);
+        assert!(matches!(res, Err(GdalError::BadArgument(_))));
+        let res = Buffer::from_vec((usize::MAX, 2), vec![0u8; 2]);
+        assert!(matches!(res, Err(GdalError::BadArgument(_))));
+    }
+
     #[test]
     #[should_panic]
     fn index_bounds_panic() {
@@ -0,0 +1,199 @@
+use std::ptr;
+
+use gdal_sys::CPLErr;
+
+use crate::cpl::CslStringList;
+use crate::errors::*;
+use crate::raster::RasterBand;
+use crate::utils::_last_cpl_err;
+use crate::vector::LayerAccess;
+
+/// Options that specify how to generate contours.
+///
+/// Attribute fields are given by name and must already exist on the
+/// destination layer; they are resolved to field indices when
+/// [`generate_contours`] is called.
+#[derive(Clone, Debug, Default)]
+pub struct ContourOptions {
+    /// The elevation interval between contours. Ignored when
+    /// `fixed_levels` is not empty.
+    pub interval: Option<f64>,
+
+    /// The base relative to which contour intervals are applied.
+    /// Defaults to `0`.
+    pub base: Option<f64>,
+
+    /// Explicit list of elevations at which to generate contours.
+    pub fixed_levels: Vec<f64>,
+
+    /// Pixel value to treat as nodata. When unset, the band's own
+    /// nodata value is not consulted.
+    pub no_data: Option<f64>,
+
+    /// Name of an integer field that receives a unique id for each
+    /// generated feature.
+    pub id_field: Option<String>,
+
+    /// Name of a real field that receives the elevation of each contour
+    /// line.
+    pub elevation_field: Option<String>,
+
+    /// Name of a real field that receives the minimum elevation of
+    /// each contour polygon. Only used with `polygonize`.
+    pub elevation_field_min: Option<String>,
+
+    /// Name of a real field that receives the maximum elevation of
+    /// each contour polygon. Only used with `polygonize`.
+    pub elevation_field_max: Option<String>,
+
+    /// Set to `true` to generate contour polygons instead of contour
+    /// lines. Defaults to `false`.
+    pub polygonize: bool,
+}
+
+impl ContourOptions {
+    /// Render these options into the `NAME=VALUE` list expected by
+    /// [`gdal_sys::GDALContourGenerateEx`], resolving field names against `layer`.
+    fn to_options_list<L: LayerAccess>(&self, layer: &L) -> Result<CslStringList> {
+        let mut options = CslStringList::new();
+
+        if let Some(interval) = self.interval {
+            options.set_name_value("LEVEL_INTERVAL", &interval.to_string())?;
+        }
+        if let Some(base) = self.base {
+            options.set_name_value("LEVEL_BASE", &base.to_string())?;
+        }
+        if !self.fixed_levels.is_empty() {
+            let levels: Vec<String> = self.fixed_levels.iter().map(f64::to_string).collect();
+            options.set_name_value("FIXED_LEVELS", &levels.join(","))?;
+        }
+        if let Some(no_data) = self.no_data {
+            options.set_name_value("NODATA", &no_data.to_string())?;
+        }
+
+        let fields = [
+            ("ID_FIELD", &self.id_field),
+            ("ELEV_FIELD", &self.elevation_field),
+            ("ELEV_FIELD_MIN", &self.elevation_field_min),
+            ("ELEV_FIELD_MAX", &self.elevation_field_max),
+        ];
+        for (key, name) in fields {
+            if let Some(name) = name {
+                let idx = layer.defn().field_index(name)?;
+                options.set_name_value(key, &idx.to_string())?;
+            }
+        }
+
+        if self.polygonize {
+            options.set_name_value("POLYGONIZE", "YES")?;
+        }
+
+        Ok(options)
+    }
+}
+
+/// Generate contour lines (or polygons) from `band` into `layer`.
+///
+/// Either [`ContourOptions::interval`] or [`ContourOptions::fixed_levels`]
+/// must be set. Generated geometries are in the georeferenced coordinates of
+/// the band's dataset.
+///
+/// See: [`GDALContourGenerateEx`](https://gdal.org/api/gdal_alg.html#_CPPv421GDALContourGenerateEx15GDALRasterBandHPv12CSLConstList16GDALProgressFuncPv)
+pub fn generate_contours<L: LayerAccess>(
+    band: &RasterBand,
+    layer: &mut L,
+    options: ContourOptions,
+) -> Result<()> {
+    if options.interval.is_none() && options.fixed_levels.is_empty() {
+        return Err(GdalError::BadArgument(
+            "either `interval` or `fixed_levels` must be set".to_string(),
+        ));
+    }
+
+    let c_options = options.to_options_list(layer)?;
+    let rv = unsafe {
+        gdal_sys::GDALContourGenerateEx(
+            band.c_rasterband(),
+            layer.c_layer(),
+            c_options.as_ptr(),
+            None,
+            ptr::null_mut(),
+        )
+    };
+    if rv != CPLErr::CE_None {
+        return Err(_last_cpl_err(rv));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use gdal_sys::{OGRFieldType, OGRwkbGeometryType};
+
+    use super::*;
+    use crate::test_utils::{memory_dataset, memory_layer, ramp_dem};
+
+    fn contour_elevations(options: ContourOptions) -> Result<Vec<f64>> {
+        let dem = ramp_dem()?;
+        let mut out = memory_dataset()?;
+        let fields = [
+            ("id", OGRFieldType::OFTInteger),
+            ("elev", OGRFieldType::OFTReal),
+        ];
+        let mut layer = memory_layer(
+            &mut out,
+            "contours",
+            OGRwkbGeometryType::wkbLineString,
+            &fields,
+        )?;
+
+        generate_contours(&dem.rasterband(1)?, &mut layer, options)?;
+
+        let elev_idx = layer.defn().field_index("elev")?;
+        let mut elevations = layer
+            .features()
+            .map(|f| f.field_as_double(elev_idx).map(|v| v.unwrap()))
+            .collect::<Result<Vec<_>>>()?;
+        elevations.sort_by(f64::total_cmp);
+        Ok(elevations)
+    }
+
+    #[test]
+    fn test_contour_interval() -> Result<()> {
+        let elevations = contour_elevations(ContourOptions {
+            interval: Some(2.0),
+            id_field: Some("id".to_string()),
+            elevation_field: Some("elev".to_string()),
+            ..Default::default()
+        })?;
+        assert_eq!(elevations, vec![2.0, 4.0, 6.0, 8.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_contour_fixed_levels() -> Result<()> {
+        let elevations = contour_elevations(ContourOptions {
+            fixed_levels: vec![3.0, 7.5],
+            elevation_field: Some("elev".to_string()),
+            ..Default::default()
+        })?;
+        assert_eq!(elevations, vec![3.0, 7.5]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_contour_unknown_field() {
+        let result = contour_elevations(ContourOptions {
+            interval: Some(2.0),
+            elevation_field: Some("height".to_string()),
+            ..Default::default()
+        });
+        assert!(matches!(result, Err(GdalError::InvalidFieldName { .. })));
+    }
+
+    #[test]
+    fn test_contour_requires_levels() {
+        let result = contour_elevations(ContourOptions::default());
+        assert!(matches!(result, Err(GdalError::BadArgument(_))));
+    }
+}
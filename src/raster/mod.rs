@@ -75,6 +75,7 @@
 //! ```
 
 pub use buffer::{Buffer, ByteBuffer};
+pub use contour::{generate_contours, ContourOptions};
 pub use create_options::RasterCreationOptions;
 pub use mdarray::{
     Attribute, Dimension, ExtendedDataType, ExtendedDataTypeClass, Group, MDArray, MdStatisticsAll,
@@ -84,15 +85,18 @@ pub use rasterband::{
     PaletteInterpretation, RasterBand, ResampleAlg, RgbaEntry, StatisticsAll, StatisticsMinMax,
 };
 pub use rasterize::{rasterize, BurnSource, MergeAlgorithm, OptimizeMode, RasterizeOptions};
+pub use rat::{RasterAttributeTable, RatFieldType, RatFieldUsage};
 pub use types::{AdjustedValue, GdalDataType, GdalType};
 pub use warp::reproject;
 
 mod buffer;
+mod contour;
 mod create_options;
 mod mdarray;
 pub mod processing;
 mod rasterband;
 mod rasterize;
+mod rat;
 #[cfg(test)]
 mod tests;
 mod types;
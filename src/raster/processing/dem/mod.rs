@@ -21,6 +21,9 @@
 //! * [`terrain_ruggedness_index()`]
 //! * [`topographic_position_index()`]
 //!
+//! [`crate::programs::raster::dem_processing`] accepts raw `gdaldem` arguments instead of
+//! a typed options struct.
+//!
 
 #![deny(missing_docs)]
 
@@ -32,7 +35,7 @@ use std::{
 
 use crate::cpl::CslStringList;
 use crate::errors::Result;
-use crate::utils::{_last_cpl_err, _path_to_c_string};
+use crate::utils::{_last_cpl_err, _last_err_or_null_pointer, _path_to_c_string};
 use crate::Dataset;
 
 pub use aspect::*;
@@ -441,7 +444,7 @@ pub fn terrain_ruggedness_index<P: AsRef<Path>>(
 }
 
 /// Execute the processor on the given [`Dataset`].
-fn dem_eval(
+pub(crate) fn dem_eval(
     src: &Dataset,
     dst_file: &Path,
     alg: DemAlg,
@@ -456,6 +459,7 @@ fn dem_eval(
 
     let mut pb_usage_error: c_int = 0;
     let out_ds = unsafe {
+        gdal_sys::CPLErrorReset();
         // Docs: https://github.com/OSGeo/gdal/blob/6a3584b2fea51f92022d24ad8036749ba1b98958/apps/gdaldem_lib.cpp#L3184
         GDALDEMProcessing(
             dest.as_ptr(),
@@ -469,6 +473,8 @@ fn dem_eval(
 
     if pb_usage_error != 0 {
         Err(_last_cpl_err(CPLErr::CE_Failure))
+    } else if out_ds.is_null() {
+        Err(_last_err_or_null_pointer("GDALDEMProcessing"))
     } else {
         let out_ds = unsafe { Dataset::from_c_dataset(out_ds) };
         Ok(out_ds)
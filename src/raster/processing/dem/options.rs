@@ -8,7 +8,7 @@ use gdal_sys::{
 
 use crate::cpl::CslStringList;
 use crate::errors;
-use crate::utils::_last_null_pointer_err;
+use crate::utils::_last_err_or_null_pointer;
 
 /// Payload for [`GDALDEMProcessing`]. Intended for internal use only.
 pub struct GdalDEMProcessingOptions(NonNull<GDALDEMProcessingOptions>);
@@ -17,10 +17,13 @@ impl GdalDEMProcessingOptions {
     pub fn new(opts: &CslStringList) -> errors::Result<Self> {
         // GDAL copies the relevant value out of `opts`, we don't need to keep them alive:
         // https://github.com/OSGeo/gdal/blob/59eaaed3168f49e8a7a3821730277aff68a86d16/apps/gdaldem_lib.cpp#L3770
-        let popts = unsafe { GDALDEMProcessingOptionsNew(opts.as_ptr(), ptr::null_mut()) };
+        let popts = unsafe {
+            gdal_sys::CPLErrorReset();
+            GDALDEMProcessingOptionsNew(opts.as_ptr(), ptr::null_mut())
+        };
         match NonNull::new(popts) {
             Some(popts) => Ok(Self(popts)),
-            None => Err(_last_null_pointer_err("GDALDEMProcessingOptionsNew")),
+            None => Err(_last_err_or_null_pointer("GDALDEMProcessingOptionsNew")),
         }
     }
 
@@ -19,7 +19,7 @@ use crate::raster::buffer::Buffer;
 use crate::raster::ResampleAlg::{
     Average, Bilinear, Cubic, CubicSpline, Gauss, Lanczos, Mode, NearestNeighbour,
 };
-use crate::raster::{GdalDataType, GdalType};
+use crate::raster::{GdalDataType, GdalType, RasterAttributeTable};
 use crate::utils::{_last_cpl_err, _last_null_pointer_err, _string};
 
 /// [Dataset] methods for raster datasets.
@@ -44,7 +44,11 @@ impl Dataset {
 
     /// Get the bands of a dataset.
     ///
-    /// Returns an iterator over the [`RasterBand`]s in the current [`Dataset`].
+    /// Returns an iterator over the [`RasterBand`]s in the current [`Dataset`], in order.
+    ///
+    /// This yields the same bands as calling [`rasterband`][Self::rasterband] with
+    /// each index from `1` to [`raster_count`][Self::raster_count], without having to
+    /// deal with GDAL's 1-based band indexing.
     ///
     /// # Example
     ///
@@ -94,6 +98,9 @@ impl Dataset {
     }
 
     /// Fetch the number of raster bands on this dataset.
+    ///
+    /// This is `0` for vector-only datasets, see [`Dataset::layer_count`] for
+    /// the number of vector layers.
     pub fn raster_count(&self) -> usize {
         (unsafe { gdal_sys::GDALGetRasterCount(self.c_dataset()) }) as usize
     }
@@ -687,12 +694,22 @@ impl<'a> RasterBand<'a> {
     }
 
     /// Returns the pixel datatype of this band.
+    ///
+    /// Complex types, which have no [`GdalDataType`] counterpart, are reported as
+    /// [`GdalDataType::Unknown`]. See [`GdalDataType::bytes`] for the size of a pixel.
+    ///
+    /// See: [`GDALGetRasterDataType`](https://gdal.org/api/raster_c_api.html#_CPPv421GDALGetRasterDataType15GDALRasterBandH)
     pub fn band_type(&self) -> GdalDataType {
         let ordinal = unsafe { gdal_sys::GDALGetRasterDataType(self.c_rasterband) };
         ordinal.try_into().unwrap_or(GdalDataType::Unknown)
     }
 
-    /// Returns the no-data value of this band.
+    /// Returns the no-data value of this band, or `None` if it has none.
+    ///
+    /// The value is 'raw', without offset and scale applied. It may be NaN for
+    /// floating point bands. For `Int64` and `UInt64` bands, whose values don't all
+    /// fit in a [`f64`], use [`no_data_value_i64`][Self::no_data_value_i64] and
+    /// [`no_data_value_u64`][Self::no_data_value_u64] instead.
     pub fn no_data_value(&self) -> Option<f64> {
         let mut pb_success = 1;
         let no_data =
@@ -727,11 +744,11 @@ impl<'a> RasterBand<'a> {
     /// The no data value returned is 'raw', meaning that it has no offset and scale applied.
     ///
     /// # Returns
-    /// No-data value as `Some(i64)` if no-data value exists, `None` otherwise.
+    /// No-data value as `Some(u64)` if no-data value exists, `None` otherwise.
     ///
     /// # Notes
     /// See also: [`GDALGetRasterNoDataValueAsUInt64`](https://gdal.org/api/raster_c_api.html#_CPPv432GDALGetRasterNoDataValueAsUInt6415GDALRasterBandHPi)
-    #[cfg(all(major_ge_3, minor_ge_5))]
+    #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
     pub fn no_data_value_u64(&self) -> Option<u64> {
         let mut pb_success = 1;
         let no_data = unsafe {
@@ -753,7 +770,7 @@ impl<'a> RasterBand<'a> {
     /// See also:
     /// [`GDALSetRasterNoDataValueAsUInt64`](https://gdal.org/api/raster_c_api.html#_CPPv432GDALSetRasterNoDataValueAsUInt6415GDALRasterBandH8uint64_t),
     /// [`GDALDeleteRasterNoDataValue`](https://gdal.org/api/raster_c_api.html#_CPPv427GDALDeleteRasterNoDataValue15GDALRasterBandH)
-    #[cfg(all(major_ge_3, minor_ge_5))]
+    #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
     pub fn set_no_data_value_u64(&mut self, no_data: Option<u64>) -> Result<()> {
         let rv = if let Some(no_data) = no_data {
             unsafe { gdal_sys::GDALSetRasterNoDataValueAsUInt64(self.c_rasterband, no_data) }
@@ -779,7 +796,7 @@ impl<'a> RasterBand<'a> {
     ///
     /// # Notes
     /// See also: [`GDALGetRasterNoDataValueAsInt64`](https://gdal.org/api/gdalrasterband_cpp.html#_CPPv4N14GDALRasterBand21GetNoDataValueAsInt64EPi)
-    #[cfg(all(major_ge_3, minor_ge_5))]
+    #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
     pub fn no_data_value_i64(&self) -> Option<i64> {
         let mut pb_success = 1;
         let no_data = unsafe {
@@ -801,7 +818,7 @@ impl<'a> RasterBand<'a> {
     /// See also:
     /// [`GDALSetRasterNoDataValueAsInt64`](https://gdal.org/api/raster_c_api.html#_CPPv431GDALSetRasterNoDataValueAsInt6415GDALRasterBandH7int64_t),
     /// [`GDALDeleteRasterNoDataValue`](https://gdal.org/api/raster_c_api.html#_CPPv427GDALDeleteRasterNoDataValue15GDALRasterBandH)
-    #[cfg(all(major_ge_3, minor_ge_5))]
+    #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
     pub fn set_no_data_value_i64(&mut self, no_data: Option<i64>) -> Result<()> {
         let rv = if let Some(no_data) = no_data {
             unsafe { gdal_sys::GDALSetRasterNoDataValueAsInt64(self.c_rasterband, no_data) }
@@ -819,6 +836,10 @@ impl<'a> RasterBand<'a> {
     /// Fill this band with a constant value.
     ///
     /// If `imaginary_value` is `None`, the imaginary component will be set to 0.
+    /// It is ignored for bands that don't have a complex data type.
+    ///
+    /// Only the full resolution band is filled; existing overviews keep their
+    /// previous contents until they are rebuilt, e.g. with [`Dataset::build_overviews`].
     ///
     /// # Notes
     /// See also:
@@ -837,10 +858,57 @@ impl<'a> RasterBand<'a> {
         Ok(())
     }
 
+    /// Compute GDAL's checksum of the pixels in a window of this band.
+    ///
+    /// # Arguments
+    /// * `window` - the window position from top left
+    /// * `window_size` - the window size
+    ///
+    /// The checksum is a 16 bit value, so different contents can collide, and it
+    /// depends on the window: the same pixels read through different windows give
+    /// different checksums. It is mostly useful to check that a raster matches
+    /// a previously recorded value, as GDAL's own test suite does.
+    ///
+    /// Returns [`GdalError::BadArgument`] if the window is not within the band.
+    ///
+    /// # Notes
+    /// See also:
+    /// [`GDALChecksumImage`](https://gdal.org/api/raster_c_api.html#_CPPv417GDALChecksumImage15GDALRasterBandHiiii)
+    pub fn checksum(&self, window: (usize, usize), window_size: (usize, usize)) -> Result<i32> {
+        let (x_size, y_size) = self.size();
+        let fits = |start: usize, len: usize, size: usize| {
+            start.checked_add(len).is_some_and(|end| end <= size)
+        };
+        if !fits(window.0, window_size.0, x_size) || !fits(window.1, window_size.1, y_size) {
+            return Err(GdalError::BadArgument(format!(
+                "checksum window {window:?} of size {window_size:?} is outside of the band of size {:?}",
+                (x_size, y_size)
+            )));
+        }
+
+        let rv = unsafe {
+            gdal_sys::GDALChecksumImage(
+                self.c_rasterband,
+                c_int::try_from(window.0)?,
+                c_int::try_from(window.1)?,
+                c_int::try_from(window_size.0)?,
+                c_int::try_from(window_size.1)?,
+            )
+        };
+        if rv < 0 {
+            return Err(_last_cpl_err(CPLErr::CE_Failure));
+        }
+        Ok(rv)
+    }
+
     /// Returns the color interpretation of this band.
+    ///
+    /// Interpretations without a [`ColorInterpretation`] variant, such as the
+    /// spectral band roles added in GDAL 3.10, are reported as
+    /// [`ColorInterpretation::Undefined`].
     pub fn color_interpretation(&self) -> ColorInterpretation {
         let interp_index = unsafe { gdal_sys::GDALGetRasterColorInterpretation(self.c_rasterband) };
-        ColorInterpretation::from_c_int(interp_index).unwrap()
+        ColorInterpretation::from_c_int(interp_index).unwrap_or(ColorInterpretation::Undefined)
     }
 
     /// Set the color interpretation for this band.
@@ -870,6 +938,31 @@ impl<'a> RasterBand<'a> {
         unsafe { GDALSetRasterColorTable(self.c_rasterband, colors.c_color_table) };
     }
 
+    /// Get the default raster attribute table of this band, if it has one.
+    ///
+    /// See: [`GDALGetDefaultRAT`](https://gdal.org/api/raster_c_api.html#_CPPv417GDALGetDefaultRAT15GDALRasterBandH)
+    pub fn rat(&self) -> Option<RasterAttributeTable<'_>> {
+        let c_rat = unsafe { gdal_sys::GDALGetDefaultRAT(self.c_rasterband) };
+        if c_rat.is_null() {
+            return None;
+        }
+        Some(RasterAttributeTable::from_c_rat(c_rat))
+    }
+
+    /// Set the default raster attribute table of this band. The table is copied.
+    ///
+    /// Most formats without native support for raster attribute tables, like GeoTIFF,
+    /// store it in a `.aux.xml` side-car file.
+    ///
+    /// See: [`GDALSetDefaultRAT`](https://gdal.org/api/raster_c_api.html#_CPPv417GDALSetDefaultRAT15GDALRasterBandH25GDALRasterAttributeTableH)
+    pub fn set_rat(&mut self, rat: &RasterAttributeTable) -> Result<()> {
+        let rv = unsafe { gdal_sys::GDALSetDefaultRAT(self.c_rasterband, rat.c_rat()) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
     /// Returns the scale of this band if set.
     pub fn scale(&self) -> Option<f64> {
         let mut pb_success = 1;
@@ -930,10 +1023,57 @@ impl<'a> RasterBand<'a> {
         Ok((block_size_x as usize, block_size_y as usize))
     }
 
+    /// Returns an iterator over the natural blocks of this band, as `(block_x, block_y, buffer)`.
+    ///
+    /// Blocks are visited row by row, following the layout of most formats, so the whole
+    /// band can be processed without holding it in memory. Unlike
+    /// [`read_block`][Self::read_block], the blocks on the right and bottom edges are clipped
+    /// to the band, and `T` doesn't have to match [`band_type`][Self::band_type]: values are
+    /// converted as with [`read_as`][Self::read_as].
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// use gdal::Dataset;
+    ///
+    /// let dataset = Dataset::open("fixtures/tinymarble.tif")?;
+    /// let band = dataset.rasterband(1)?;
+    /// let mut sum = 0.0;
+    /// for block in band.blocks::<f64>() {
+    ///     let (_, _, buffer) = block?;
+    ///     sum += buffer.data().iter().sum::<f64>();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn blocks<T: Copy + GdalType>(
+        &self,
+    ) -> impl Iterator<Item = Result<(usize, usize, Buffer<T>)>> + '_ {
+        let (block_x_size, block_y_size) = self.block_size();
+        let (x_size, y_size) = self.size();
+        let blocks_x = x_size.div_ceil(block_x_size);
+        let blocks_y = y_size.div_ceil(block_y_size);
+        (0..blocks_y)
+            .flat_map(move |y| (0..blocks_x).map(move |x| (x, y)))
+            .map(move |(x, y)| {
+                let size = self.actual_block_size(x, y)?;
+                let window = ((x * block_x_size) as isize, (y * block_y_size) as isize);
+                let buffer = self.read_as::<T>(window, size, size, None)?;
+                Ok((x, y, buffer))
+            })
+    }
+
+    /// Returns the number of overviews of this band.
+    ///
+    /// See also: [`Dataset::build_overviews`]
     pub fn overview_count(&self) -> Result<i32> {
         unsafe { Ok(gdal_sys::GDALGetOverviewCount(self.c_rasterband)) }
     }
 
+    /// Fetch the overview at `overview_index`.
+    ///
+    /// Overviews are usually ordered from the largest to the smallest.
     pub fn overview(&self, overview_index: usize) -> Result<RasterBand<'a>> {
         let overview_index = c_int::try_from(overview_index)?;
 
@@ -947,6 +1087,24 @@ impl<'a> RasterBand<'a> {
         }
     }
 
+    /// Fetch the most reduced overview of this band that still has at least
+    /// `size.0 * size.1` pixels, to read a raster at a target resolution.
+    ///
+    /// Returns this band itself if it has no overviews, or none of them is large enough.
+    ///
+    /// # Notes
+    /// See also:
+    /// [`GDALGetRasterSampleOverviewEx`](https://gdal.org/api/raster_c_api.html#_CPPv429GDALGetRasterSampleOverviewEx15GDALRasterBandH9GUIntBig)
+    pub fn overview_for_size(&self, size: (usize, usize)) -> Result<RasterBand<'a>> {
+        let samples = (size.0 as u64).saturating_mul(size.1 as u64);
+        let overview =
+            unsafe { gdal_sys::GDALGetRasterSampleOverviewEx(self.c_rasterband, samples) };
+        if overview.is_null() {
+            return Err(_last_null_pointer_err("GDALGetRasterSampleOverviewEx"));
+        }
+        Ok(unsafe { RasterBand::from_c_rasterband(self.dataset, overview) })
+    }
+
     /// Return the unit of the rasterband.
     /// If there is no unit, the empty string is returned.
     pub fn unit(&self) -> String {
@@ -1036,19 +1194,34 @@ impl<'a> RasterBand<'a> {
     ///
     /// If `is_approx_ok` is `false`, then all pixels will be read and used to compute an exact range.
     ///
+    /// This is cheaper than [`get_statistics`](Self::get_statistics) when only the range is
+    /// needed, e.g. for stretching the values for display, since no mean or standard deviation
+    /// is computed, and nothing is cached in the dataset metadata.
+    ///
+    /// Starting with GDAL 3.6, an error is returned if the range can't be computed, e.g. when
+    /// all the pixels are nodata. Older versions return an unspecified range.
+    ///
     /// This methods is a wrapper for [`GDALComputeRasterMinMax`](https://gdal.org/api/gdalrasterband_cpp.html#_CPPv4N14GDALRasterBand19ComputeRasterMinMaxEiPd).
     ///
     pub fn compute_raster_min_max(&self, is_approx_ok: bool) -> Result<StatisticsMinMax> {
         let mut min_max = [0., 0.];
 
-        // TODO: The C++ method actually returns a CPLErr, but the C interface does not expose it.
-        unsafe {
+        // Before GDAL 3.6, the C function doesn't report errors.
+        #[cfg_attr(
+            not(any(all(major_ge_3, minor_ge_6), major_ge_4)),
+            allow(clippy::let_unit_value, unused_variables)
+        )]
+        let rv = unsafe {
             GDALComputeRasterMinMax(
                 self.c_rasterband,
                 c_int::from(is_approx_ok),
                 &mut min_max as *mut f64,
             )
         };
+        #[cfg(any(all(major_ge_3, minor_ge_6), major_ge_4))]
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
 
         Ok(StatisticsMinMax {
             min: min_max[0],
@@ -1348,11 +1521,14 @@ impl ColorInterpretation {
     }
 
     /// Creates a color interpretation from its name.
+    ///
+    /// Unknown names, and names of interpretations without a variant here, give
+    /// [`ColorInterpretation::Undefined`].
     pub fn from_name(name: &str) -> Result<Self> {
         let c_str_interp_name = CString::new(name)?;
         let interp_index =
             unsafe { gdal_sys::GDALGetColorInterpretationByName(c_str_interp_name.as_ptr()) };
-        Ok(Self::from_c_int(interp_index).unwrap())
+        Ok(Self::from_c_int(interp_index).unwrap_or(Self::Undefined))
     }
 
     /// Returns the name of this color interpretation.
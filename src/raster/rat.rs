@@ -0,0 +1,360 @@
+use std::ffi::{c_int, CString};
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+use gdal_sys::{CPLErr, GDALRATFieldType, GDALRATFieldUsage, GDALRasterAttributeTableH};
+
+use crate::errors::*;
+use crate::raster::RasterBand;
+use crate::utils::{_last_cpl_err, _string};
+
+/// Data type of a [`RasterAttributeTable`] column.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RatFieldType {
+    /// Integer values
+    Integer,
+    /// Floating point values
+    Real,
+    /// String values
+    String,
+}
+
+impl RatFieldType {
+    fn from_c_int(field_type: GDALRATFieldType::Type) -> Self {
+        match field_type {
+            GDALRATFieldType::GFT_Integer => Self::Integer,
+            GDALRATFieldType::GFT_Real => Self::Real,
+            _ => Self::String,
+        }
+    }
+
+    /// Returns the C API int value of this field type.
+    pub fn c_int(&self) -> GDALRATFieldType::Type {
+        match self {
+            Self::Integer => GDALRATFieldType::GFT_Integer,
+            Self::Real => GDALRATFieldType::GFT_Real,
+            Self::String => GDALRATFieldType::GFT_String,
+        }
+    }
+}
+
+/// Meaning of the values of a [`RasterAttributeTable`] column.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RatFieldUsage {
+    /// General purpose field
+    Generic,
+    /// Histogram pixel count
+    PixelCount,
+    /// Class name
+    Name,
+    /// Class range minimum
+    Min,
+    /// Class range maximum
+    Max,
+    /// Class value, minimum and maximum at once
+    MinMax,
+    /// Red class color, `0..=255`
+    Red,
+    /// Green class color, `0..=255`
+    Green,
+    /// Blue class color, `0..=255`
+    Blue,
+    /// Alpha class transparency, `0..=255`
+    Alpha,
+    /// Color range red minimum
+    RedMin,
+    /// Color range green minimum
+    GreenMin,
+    /// Color range blue minimum
+    BlueMin,
+    /// Color range alpha minimum
+    AlphaMin,
+    /// Color range red maximum
+    RedMax,
+    /// Color range green maximum
+    GreenMax,
+    /// Color range blue maximum
+    BlueMax,
+    /// Color range alpha maximum
+    AlphaMax,
+}
+
+impl RatFieldUsage {
+    fn from_c_int(usage: GDALRATFieldUsage::Type) -> Self {
+        match usage {
+            GDALRATFieldUsage::GFU_PixelCount => Self::PixelCount,
+            GDALRATFieldUsage::GFU_Name => Self::Name,
+            GDALRATFieldUsage::GFU_Min => Self::Min,
+            GDALRATFieldUsage::GFU_Max => Self::Max,
+            GDALRATFieldUsage::GFU_MinMax => Self::MinMax,
+            GDALRATFieldUsage::GFU_Red => Self::Red,
+            GDALRATFieldUsage::GFU_Green => Self::Green,
+            GDALRATFieldUsage::GFU_Blue => Self::Blue,
+            GDALRATFieldUsage::GFU_Alpha => Self::Alpha,
+            GDALRATFieldUsage::GFU_RedMin => Self::RedMin,
+            GDALRATFieldUsage::GFU_GreenMin => Self::GreenMin,
+            GDALRATFieldUsage::GFU_BlueMin => Self::BlueMin,
+            GDALRATFieldUsage::GFU_AlphaMin => Self::AlphaMin,
+            GDALRATFieldUsage::GFU_RedMax => Self::RedMax,
+            GDALRATFieldUsage::GFU_GreenMax => Self::GreenMax,
+            GDALRATFieldUsage::GFU_BlueMax => Self::BlueMax,
+            GDALRATFieldUsage::GFU_AlphaMax => Self::AlphaMax,
+            _ => Self::Generic,
+        }
+    }
+
+    /// Returns the C API int value of this field usage.
+    pub fn c_int(&self) -> GDALRATFieldUsage::Type {
+        match self {
+            Self::Generic => GDALRATFieldUsage::GFU_Generic,
+            Self::PixelCount => GDALRATFieldUsage::GFU_PixelCount,
+            Self::Name => GDALRATFieldUsage::GFU_Name,
+            Self::Min => GDALRATFieldUsage::GFU_Min,
+            Self::Max => GDALRATFieldUsage::GFU_Max,
+            Self::MinMax => GDALRATFieldUsage::GFU_MinMax,
+            Self::Red => GDALRATFieldUsage::GFU_Red,
+            Self::Green => GDALRATFieldUsage::GFU_Green,
+            Self::Blue => GDALRATFieldUsage::GFU_Blue,
+            Self::Alpha => GDALRATFieldUsage::GFU_Alpha,
+            Self::RedMin => GDALRATFieldUsage::GFU_RedMin,
+            Self::GreenMin => GDALRATFieldUsage::GFU_GreenMin,
+            Self::BlueMin => GDALRATFieldUsage::GFU_BlueMin,
+            Self::AlphaMin => GDALRATFieldUsage::GFU_AlphaMin,
+            Self::RedMax => GDALRATFieldUsage::GFU_RedMax,
+            Self::GreenMax => GDALRATFieldUsage::GFU_GreenMax,
+            Self::BlueMax => GDALRATFieldUsage::GFU_BlueMax,
+            Self::AlphaMax => GDALRATFieldUsage::GFU_AlphaMax,
+        }
+    }
+}
+
+/// A raster attribute table, mapping the pixel values of a [`RasterBand`] to attributes,
+/// such as the names and colors of land cover classes.
+///
+/// Read the table of a band with [`RasterBand::rat`], and attach one with [`RasterBand::set_rat`].
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use gdal::raster::{RasterAttributeTable, RatFieldType, RatFieldUsage};
+/// use gdal::DriverManager;
+/// # fn main() -> gdal::errors::Result<()> {
+/// let mut rat = RasterAttributeTable::new();
+/// rat.create_column("value", RatFieldType::Integer, RatFieldUsage::MinMax)?;
+/// rat.create_column("class", RatFieldType::String, RatFieldUsage::Name)?;
+/// rat.set_row_count(2)?;
+/// rat.set_value_as_int(0, 0, 1)?;
+/// rat.set_value_as_string(0, 1, "forest")?;
+/// rat.set_value_as_int(1, 0, 2)?;
+/// rat.set_value_as_string(1, 1, "water")?;
+///
+/// let driver = DriverManager::get_driver_by_name("MEM")?;
+/// let ds = driver.create("", 10, 10, 1)?;
+/// let mut band = ds.rasterband(1)?;
+/// band.set_rat(&rat)?;
+/// assert_eq!(band.rat().unwrap().get_value_as_string(1, 1)?, "water");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See: [`GDALRasterAttributeTable`](https://gdal.org/api/gdalrasterattributetable_cpp.html)
+pub struct RasterAttributeTable<'a> {
+    c_rat: GDALRasterAttributeTableH,
+    /// If `true`, Rust is responsible for deallocating the table pointed to by `c_rat`,
+    /// which is the case when instantiated directly, as opposed to when read via
+    /// [`RasterBand::rat`].
+    rust_owned: bool,
+    phantom_raster_band: PhantomData<&'a RasterBand<'a>>,
+}
+
+impl<'a> RasterAttributeTable<'a> {
+    /// Instantiate a new, empty raster attribute table.
+    pub fn new() -> Self {
+        let c_rat = unsafe { gdal_sys::GDALCreateRasterAttributeTable() };
+        Self {
+            c_rat,
+            rust_owned: true,
+            phantom_raster_band: PhantomData,
+        }
+    }
+
+    /// Wrap a C raster attribute table owned by a band.
+    pub(crate) fn from_c_rat(c_rat: GDALRasterAttributeTableH) -> Self {
+        Self {
+            c_rat,
+            rust_owned: false,
+            phantom_raster_band: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped C pointer
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_rat(&self) -> GDALRasterAttributeTableH {
+        self.c_rat
+    }
+
+    /// Returns the number of columns.
+    pub fn column_count(&self) -> usize {
+        unsafe { gdal_sys::GDALRATGetColumnCount(self.c_rat) as usize }
+    }
+
+    /// Returns the number of rows.
+    pub fn row_count(&self) -> usize {
+        unsafe { gdal_sys::GDALRATGetRowCount(self.c_rat) as usize }
+    }
+
+    /// Resize the table to `row_count` rows. New rows are filled with empty values.
+    pub fn set_row_count(&mut self, row_count: usize) -> Result<()> {
+        let row_count = c_int::try_from(row_count)?;
+        unsafe { gdal_sys::GDALRATSetRowCount(self.c_rat, row_count) };
+        Ok(())
+    }
+
+    /// Append a column.
+    ///
+    /// See: [`GDALRATCreateColumn`](https://gdal.org/api/raster_c_api.html#_CPPv419GDALRATCreateColumn25GDALRasterAttributeTableHPKc16GDALRATFieldType17GDALRATFieldUsage)
+    pub fn create_column(
+        &mut self,
+        name: &str,
+        field_type: RatFieldType,
+        usage: RatFieldUsage,
+    ) -> Result<()> {
+        let c_name = CString::new(name)?;
+        let rv = unsafe {
+            gdal_sys::GDALRATCreateColumn(
+                self.c_rat,
+                c_name.as_ptr(),
+                field_type.c_int(),
+                usage.c_int(),
+            )
+        };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
+    /// Returns the name of column `col`, or `None` if there is no such column.
+    pub fn column_name(&self, col: usize) -> Option<String> {
+        let col = self.column_index(col).ok()?;
+        _string(unsafe { gdal_sys::GDALRATGetNameOfCol(self.c_rat, col) })
+    }
+
+    /// Returns the usage of column `col`.
+    pub fn column_usage(&self, col: usize) -> Result<RatFieldUsage> {
+        let col = self.column_index(col)?;
+        let usage = unsafe { gdal_sys::GDALRATGetUsageOfCol(self.c_rat, col) };
+        Ok(RatFieldUsage::from_c_int(usage))
+    }
+
+    /// Returns the data type of column `col`.
+    pub fn column_type(&self, col: usize) -> Result<RatFieldType> {
+        let col = self.column_index(col)?;
+        let field_type = unsafe { gdal_sys::GDALRATGetTypeOfCol(self.c_rat, col) };
+        Ok(RatFieldType::from_c_int(field_type))
+    }
+
+    /// Returns the index of the first column with the given `usage`, if any.
+    pub fn column_of_usage(&self, usage: RatFieldUsage) -> Option<usize> {
+        let col = unsafe { gdal_sys::GDALRATGetColOfUsage(self.c_rat, usage.c_int()) };
+        usize::try_from(col).ok()
+    }
+
+    /// Returns the row holding pixel `value`, based on the `Min`, `Max` and `MinMax`
+    /// columns, or on the linear binning of the table.
+    pub fn row_of_value(&self, value: f64) -> Option<usize> {
+        let row = unsafe { gdal_sys::GDALRATGetRowOfValue(self.c_rat, value) };
+        usize::try_from(row).ok()
+    }
+
+    /// Returns the value at `row` and `col`, formatted as a string.
+    pub fn get_value_as_string(&self, row: usize, col: usize) -> Result<String> {
+        let (row, col) = self.cell_index(row, col)?;
+        let c_ptr = unsafe { gdal_sys::GDALRATGetValueAsString(self.c_rat, row, col) };
+        Ok(_string(c_ptr).unwrap_or_default())
+    }
+
+    /// Returns the value at `row` and `col`, converted to an integer.
+    pub fn get_value_as_int(&self, row: usize, col: usize) -> Result<i32> {
+        let (row, col) = self.cell_index(row, col)?;
+        Ok(unsafe { gdal_sys::GDALRATGetValueAsInt(self.c_rat, row, col) })
+    }
+
+    /// Returns the value at `row` and `col`, converted to a floating point number.
+    pub fn get_value_as_double(&self, row: usize, col: usize) -> Result<f64> {
+        let (row, col) = self.cell_index(row, col)?;
+        Ok(unsafe { gdal_sys::GDALRATGetValueAsDouble(self.c_rat, row, col) })
+    }
+
+    /// Set the value at `row` and `col`, converting it to the type of the column.
+    pub fn set_value_as_string(&mut self, row: usize, col: usize, value: &str) -> Result<()> {
+        let (row, col) = self.cell_index(row, col)?;
+        let c_value = CString::new(value)?;
+        unsafe { gdal_sys::GDALRATSetValueAsString(self.c_rat, row, col, c_value.as_ptr()) };
+        Ok(())
+    }
+
+    /// Set the value at `row` and `col`, converting it to the type of the column.
+    pub fn set_value_as_int(&mut self, row: usize, col: usize, value: i32) -> Result<()> {
+        let (row, col) = self.cell_index(row, col)?;
+        unsafe { gdal_sys::GDALRATSetValueAsInt(self.c_rat, row, col, value) };
+        Ok(())
+    }
+
+    /// Set the value at `row` and `col`, converting it to the type of the column.
+    pub fn set_value_as_double(&mut self, row: usize, col: usize, value: f64) -> Result<()> {
+        let (row, col) = self.cell_index(row, col)?;
+        unsafe { gdal_sys::GDALRATSetValueAsDouble(self.c_rat, row, col, value) };
+        Ok(())
+    }
+
+    fn column_index(&self, col: usize) -> Result<c_int> {
+        if col >= self.column_count() {
+            return Err(GdalError::BadArgument(format!(
+                "column {col} is out of range, the table has {} columns",
+                self.column_count()
+            )));
+        }
+        Ok(col as c_int)
+    }
+
+    fn cell_index(&self, row: usize, col: usize) -> Result<(c_int, c_int)> {
+        let col = self.column_index(col)?;
+        if row >= self.row_count() {
+            return Err(GdalError::BadArgument(format!(
+                "row {row} is out of range, the table has {} rows",
+                self.row_count()
+            )));
+        }
+        Ok((row as c_int, col))
+    }
+}
+
+impl Drop for RasterAttributeTable<'_> {
+    fn drop(&mut self) {
+        if self.rust_owned {
+            unsafe { gdal_sys::GDALDestroyRasterAttributeTable(self.c_rat) }
+        }
+    }
+}
+
+impl Default for RasterAttributeTable<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for RasterAttributeTable<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let columns = (0..self.column_count())
+            .filter_map(|i| self.column_name(i))
+            .collect::<Vec<_>>();
+
+        f.debug_struct("RasterAttributeTable")
+            .field("columns", &columns)
+            .field("row_count", &self.row_count())
+            .finish()
+    }
+}
@@ -1,12 +1,12 @@
 use crate::dataset::Dataset;
-use crate::errors::Result;
+use crate::errors::{GdalError, Result};
 use crate::metadata::Metadata;
 use crate::raster::rasterband::ResampleAlg;
 use crate::raster::{
-    ByteBuffer, ColorEntry, ColorInterpretation, ColorTable, GdalDataType, RasterCreationOptions,
-    StatisticsAll, StatisticsMinMax,
+    ByteBuffer, ColorEntry, ColorInterpretation, ColorTable, GdalDataType, RasterAttributeTable,
+    RasterCreationOptions, RatFieldType, RatFieldUsage, StatisticsAll, StatisticsMinMax,
 };
-use crate::test_utils::{fixture, TempFixture};
+use crate::test_utils::{fixture, SuppressGDALErrorLog, TempFixture};
 use crate::vsi::unlink_mem_file;
 use crate::DriverManager;
 use std::path::Path;
@@ -70,6 +70,23 @@ fn test_read_rasterbands() {
     assert_eq!(dataset.rasterbands().count(), 3);
 }
 
+#[test]
+fn test_rasterbands_match_indexed_access() {
+    let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+    let bands = dataset.rasterbands().collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(bands.len(), dataset.raster_count());
+    for (i, band) in bands.iter().enumerate() {
+        let indexed = dataset.rasterband(i + 1).unwrap();
+        assert_eq!(unsafe { band.c_rasterband() }, unsafe {
+            indexed.c_rasterband()
+        });
+    }
+
+    let _nolog = SuppressGDALErrorLog::new();
+    assert!(dataset.rasterband(0).is_err());
+    assert!(dataset.rasterband(dataset.raster_count() + 1).is_err());
+}
+
 #[test]
 fn test_read_raster_with_default_resample() {
     let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
@@ -150,6 +167,44 @@ fn test_rename_remove_raster() {
     assert!(Dataset::open(mem_file_path_b).is_err());
 }
 
+#[test]
+fn test_rename_remove_raster_sidecars() -> Result<()> {
+    let dir = Path::new("/vsimem/test_rename_remove_raster_sidecars");
+    let listing = || {
+        let mut files = crate::vsi::read_dir(dir, false)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|p| p.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        files.sort();
+        files
+    };
+    let driver = DriverManager::get_driver_by_name("GTiff")?;
+    {
+        let dataset = Dataset::open(fixture("tinymarble.tif"))?;
+        dataset.create_copy(&driver, dir.join("a.tif"), &Default::default())?;
+    }
+    {
+        // overviews of a dataset opened read-only are written to an external `.ovr`
+        let mut dataset = Dataset::open(dir.join("a.tif"))?;
+        dataset.build_overviews("NEAREST", &[2], &[])?;
+    }
+    assert_eq!(listing(), ["a.tif", "a.tif.ovr"]);
+
+    driver.rename(dir.join("b.tif"), dir.join("a.tif"))?;
+    assert_eq!(listing(), ["b.tif", "b.tif.ovr"]);
+    assert_eq!(
+        Dataset::open(dir.join("b.tif"))?
+            .rasterband(1)?
+            .overview_count()?,
+        1
+    );
+
+    driver.delete(dir.join("b.tif"))?;
+    assert!(listing().is_empty());
+    Ok(())
+}
+
 #[test]
 fn test_create() {
     let driver = DriverManager::get_driver_by_name("MEM").unwrap();
@@ -204,6 +259,41 @@ fn test_create_with_band_type_with_options() {
     assert_eq!(meta.as_deref(), Some("LZW"));
 }
 
+#[test]
+fn test_create_write_and_reopen() -> Result<()> {
+    let path = "/vsimem/test_create_write_and_reopen.tif";
+    let driver = DriverManager::get_driver_by_name("GTiff")?;
+    {
+        let dataset = driver.create_with_data_type(
+            path,
+            256,
+            256,
+            1,
+            GdalDataType::UInt8,
+            &RasterCreationOptions::default(),
+        )?;
+        let mut band = dataset.rasterband(1)?;
+        let data = (0..256 * 256).map(|i| (i % 256) as u8).collect();
+        let mut buffer = ByteBuffer::new((256, 256), data);
+        band.write((0, 0), (256, 256), &mut buffer)?;
+    }
+
+    let dataset = Dataset::open(path)?;
+    assert_eq!(dataset.raster_size(), (256, 256));
+    let band = dataset.rasterband(1)?;
+    assert_eq!(band.band_type(), GdalDataType::UInt8);
+    let row = band.read_as::<u8>((0, 10), (256, 1), (256, 1), None)?;
+    assert_eq!(row.data()[42], 42);
+    drop(dataset);
+    unlink_mem_file(path)?;
+
+    // AAIGrid only supports `CreateCopy`
+    let _nolog = SuppressGDALErrorLog::new();
+    let driver = DriverManager::get_driver_by_name("AAIGrid")?;
+    assert!(driver.create("/vsimem/test_create.asc", 8, 8, 1).is_err());
+    Ok(())
+}
+
 #[test]
 fn test_create_copy() {
     let driver = DriverManager::get_driver_by_name("MEM").unwrap();
@@ -245,6 +335,44 @@ fn test_create_copy_with_options() {
     unlink_mem_file(mem_file_path).unwrap();
 }
 
+#[test]
+fn test_create_copy_with_progress() -> Result<()> {
+    use std::{cell::Cell, rc::Rc};
+
+    let dataset = Dataset::open(fixture("tinymarble.tif"))?;
+    let driver = DriverManager::get_driver_by_name("GTiff")?;
+    let options = RasterCreationOptions::from_iter(["TILED=YES", "COMPRESS=DEFLATE"]);
+    let mem_file_path = "/vsimem/test_create_copy_with_progress.tif";
+
+    let last = Rc::new(Cell::new(0.0));
+    let reported = last.clone();
+    let copy = dataset.create_copy_with_progress(
+        &driver,
+        mem_file_path,
+        &options,
+        move |complete, _| {
+            reported.set(complete);
+            true
+        },
+    )?;
+    assert_eq!(last.get(), 1.0);
+    assert_eq!(
+        copy.metadata_item("COMPRESSION", "IMAGE_STRUCTURE")
+            .as_deref(),
+        Some("DEFLATE")
+    );
+    assert_eq!(copy.rasterband(1)?.block_size(), (256, 256));
+    drop(copy);
+    unlink_mem_file(mem_file_path)?;
+
+    // cancelling the copy fails it
+    let _nolog = SuppressGDALErrorLog::new();
+    let result = dataset.create_copy_with_progress(&driver, mem_file_path, &options, |_, _| false);
+    assert!(result.is_err());
+    let _ = unlink_mem_file(mem_file_path);
+    Ok(())
+}
+
 #[test]
 #[allow(clippy::float_cmp)]
 fn test_geo_transform() {
@@ -374,6 +502,24 @@ fn test_read_block_data() {
     assert_eq!(buf.data()[99], 51);
 }
 
+#[test]
+fn test_blocks() {
+    let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+    let band = dataset.rasterband(1).unwrap();
+    let blocks = band.blocks::<u32>().collect::<Result<Vec<_>>>().unwrap();
+    let indices: Vec<_> = blocks.iter().map(|(x, y, _)| (*x, *y)).collect();
+    assert_eq!(indices, [(0, 0), (0, 1)]);
+    assert_eq!(blocks[0].2.shape(), (100, 27));
+    // the last block row is clipped to the band
+    assert_eq!(blocks[1].2.shape(), (100, 23));
+
+    let total: u32 = blocks.iter().flat_map(|(_, _, buffer)| buffer.data()).sum();
+    let full = band
+        .read_as::<u32>((0, 0), (100, 50), (100, 50), None)
+        .unwrap();
+    assert_eq!(total, full.data().iter().sum::<u32>());
+}
+
 #[test]
 #[cfg(feature = "ndarray")]
 fn test_write_block() {
@@ -418,6 +564,16 @@ fn test_get_band_type() {
     let dataset = driver.create("", 20, 10, 1).unwrap();
     let rb = dataset.rasterband(1).unwrap();
     assert_eq!(rb.band_type(), GdalDataType::UInt8);
+
+    let dataset = driver
+        .create_with_band_type::<f64, _>("", 20, 10, 1)
+        .unwrap();
+    let band_type = dataset.rasterband(1).unwrap().band_type();
+    assert_eq!(band_type, GdalDataType::Float64);
+    assert_eq!(band_type.bytes(), 8);
+    assert!(band_type.is_floating());
+    assert!(!band_type.is_integer());
+    assert!(band_type.is_conversion_lossy(GdalDataType::Float32));
 }
 
 #[test]
@@ -445,7 +601,7 @@ fn test_get_no_data_value() -> Result<()> {
 }
 
 #[test]
-#[cfg(all(major_ge_3, minor_ge_5))]
+#[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
 fn test_no_data_value_i64() -> Result<()> {
     let driver = DriverManager::get_driver_by_name("MEM")?;
     let ds = driver.create_with_band_type::<i64, _>("test_no_data_value_i64", 1, 1, 1)?;
@@ -458,7 +614,7 @@ fn test_no_data_value_i64() -> Result<()> {
 }
 
 #[test]
-#[cfg(all(major_ge_3, minor_ge_5))]
+#[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
 fn test_no_data_value_u64() -> Result<()> {
     let driver = DriverManager::get_driver_by_name("MEM")?;
     let ds = driver.create_with_band_type::<u64, _>("test_no_data_value_u64", 1, 1, 1)?;
@@ -483,6 +639,26 @@ fn test_set_no_data_value() {
     assert_eq!(rasterband.no_data_value(), None);
 }
 
+#[test]
+fn test_no_data_value_float_band() -> Result<()> {
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let dataset = driver.create_with_band_type::<f32, _>("", 4, 4, 1)?;
+    let mut rasterband = dataset.rasterband(1)?;
+    assert!(rasterband.mask_flags()?.is_all_valid());
+
+    rasterband.set_no_data_value(Some(-9999.0))?;
+    assert_eq!(rasterband.no_data_value(), Some(-9999.0));
+    assert!(rasterband.mask_flags()?.is_nodata());
+
+    rasterband.set_no_data_value(Some(f64::NAN))?;
+    assert!(rasterband.no_data_value().unwrap().is_nan());
+
+    rasterband.set_no_data_value(None)?;
+    assert_eq!(rasterband.no_data_value(), None);
+    assert!(rasterband.mask_flags()?.is_all_valid());
+    Ok(())
+}
+
 #[test]
 fn test_fill() {
     let driver = DriverManager::get_driver_by_name("MEM").unwrap();
@@ -495,6 +671,48 @@ fn test_fill() {
     assert!(contents.data().iter().all(|&v| v == 5));
 }
 
+#[test]
+fn test_fill_corners() -> Result<()> {
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let dataset = driver.create_with_band_type::<u8, _>("", 8, 6, 1)?;
+    let mut rasterband = dataset.rasterband(1)?;
+    rasterband.fill(255.0, None)?;
+    for (x, y) in [(0, 0), (7, 0), (0, 5), (7, 5)] {
+        let pixel = rasterband.read_as::<u8>((x, y), (1, 1), (1, 1), None)?;
+        assert_eq!(pixel.data(), [255]);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_checksum() -> Result<()> {
+    let dataset = Dataset::open(fixture("tinymarble.tif"))?;
+    let rasterband = dataset.rasterband(1)?;
+    let full = rasterband.checksum((0, 0), rasterband.size())?;
+    assert!((0..=0xffff).contains(&full));
+    assert_ne!(rasterband.checksum((0, 0), (10, 10))?, full);
+
+    // an in-memory copy has the same checksum, until a pixel changes
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let copy = dataset.create_copy(&driver, "", &RasterCreationOptions::default())?;
+    let mut band = copy.rasterband(1)?;
+    assert_eq!(band.checksum((0, 0), band.size())?, full);
+    let pixel = band.read_as::<u8>((0, 0), (1, 1), (1, 1), None)?.data()[0];
+    let mut buffer = ByteBuffer::new((1, 1), vec![pixel.wrapping_add(1)]);
+    band.write((0, 0), (1, 1), &mut buffer)?;
+    assert_ne!(band.checksum((0, 0), band.size())?, full);
+
+    assert!(matches!(
+        rasterband.checksum((90, 0), (20, 10)),
+        Err(GdalError::BadArgument(_))
+    ));
+    assert!(matches!(
+        rasterband.checksum((1, 0), (usize::MAX, 10)),
+        Err(GdalError::BadArgument(_))
+    ));
+    Ok(())
+}
+
 #[test]
 fn test_get_scale() {
     let dataset = Dataset::open(fixture("offset_scaled_tinymarble.tif")).unwrap();
@@ -581,6 +799,24 @@ fn test_fail_read_overviews() {
     assert!(overview_2.is_err());
 }
 
+#[test]
+fn test_build_and_pick_overviews() {
+    let fixture = TempFixture::fixture("offset_scaled_tinymarble.tif");
+    let mut dataset = Dataset::open(&fixture).unwrap();
+    dataset.build_overviews("NEAREST", &[2, 4], &[]).unwrap();
+
+    let rasterband = dataset.rasterband(1).unwrap();
+    assert_eq!(rasterband.overview_count().unwrap(), 2);
+    assert_eq!(rasterband.overview(0).unwrap().size(), (50, 25));
+
+    let pick = |size| rasterband.overview_for_size(size).unwrap().size();
+    assert_eq!(pick((30, 15)), (50, 25));
+    assert_eq!(pick((25, 13)), (25, 13));
+    assert_eq!(pick((1, 1)), (25, 13));
+    // no overview is large enough
+    assert_eq!(pick((80, 40)), (100, 50));
+}
+
 #[test]
 fn test_rasterband_lifetime() {
     let dataset: Dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
@@ -614,6 +850,50 @@ fn test_set_rasterband_color_interp() {
     assert_eq!(band_interp, ColorInterpretation::AlphaBand);
 }
 
+#[test]
+fn test_rasterband_color_interp_rgba_order() {
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let dataset = driver.create("", 1, 1, 4).unwrap();
+    let order = [
+        ColorInterpretation::BlueBand,
+        ColorInterpretation::GreenBand,
+        ColorInterpretation::RedBand,
+        ColorInterpretation::AlphaBand,
+    ];
+    for (band, interp) in dataset.rasterbands().zip(order) {
+        band.unwrap().set_color_interpretation(interp).unwrap();
+    }
+
+    let alpha = dataset
+        .rasterbands()
+        .position(|band| band.unwrap().color_interpretation() == ColorInterpretation::AlphaBand);
+    assert_eq!(alpha, Some(3));
+    let red = dataset.rasterband(3).unwrap().color_interpretation();
+    assert_eq!(red, ColorInterpretation::RedBand);
+}
+
+#[test]
+#[cfg(any(all(major_ge_3, minor_ge_10), major_ge_4))]
+fn test_rasterband_color_interp_unmapped() {
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let dataset = driver.create("", 1, 1, 1).unwrap();
+    let rasterband = dataset.rasterband(1).unwrap();
+    unsafe {
+        gdal_sys::GDALSetRasterColorInterpretation(
+            rasterband.c_rasterband(),
+            gdal_sys::GDALColorInterp::GCI_NIRBand,
+        );
+    }
+    assert_eq!(
+        rasterband.color_interpretation(),
+        ColorInterpretation::Undefined
+    );
+    assert_eq!(
+        ColorInterpretation::from_name("NIR").unwrap(),
+        ColorInterpretation::Undefined
+    );
+}
+
 #[test]
 fn test_set_rasterband_scale() {
     let driver = DriverManager::get_driver_by_name("MEM").unwrap();
@@ -723,6 +1003,61 @@ fn test_color_table() {
     }
 }
 
+#[test]
+fn test_raster_attribute_table() {
+    let outfile = TempFixture::empty("rat_labels.tif");
+    {
+        let dataset = Dataset::open(fixture("labels.tif")).unwrap();
+        assert!(dataset.rasterband(1).unwrap().rat().is_none());
+        let dataset = dataset
+            .create_copy(&dataset.driver(), &outfile, &Default::default())
+            .unwrap();
+
+        let mut rat = RasterAttributeTable::new();
+        rat.create_column("value", RatFieldType::Integer, RatFieldUsage::MinMax)
+            .unwrap();
+        rat.create_column("class", RatFieldType::String, RatFieldUsage::Name)
+            .unwrap();
+        rat.create_column("area", RatFieldType::Real, RatFieldUsage::Generic)
+            .unwrap();
+        rat.set_row_count(3).unwrap();
+        assert!(rat.set_row_count(usize::MAX).is_err());
+        assert_eq!(rat.row_count(), 3);
+        for (row, (class, area)) in [("forest", 1.5), ("water", 0.25), ("urban", 4.0)]
+            .into_iter()
+            .enumerate()
+        {
+            rat.set_value_as_int(row, 0, row as i32 + 1).unwrap();
+            rat.set_value_as_string(row, 1, class).unwrap();
+            rat.set_value_as_double(row, 2, area).unwrap();
+        }
+        assert!(rat.set_value_as_int(0, 3, 0).is_err());
+        dataset.rasterband(1).unwrap().set_rat(&rat).unwrap();
+    }
+
+    // Reopen to confirm the table was saved.
+    let dataset = Dataset::open(&outfile).unwrap();
+    let band = dataset.rasterband(1).unwrap();
+    let rat = band.rat().expect("saved raster attribute table");
+    assert_eq!(rat.column_count(), 3);
+    assert_eq!(rat.row_count(), 3);
+    assert_eq!(rat.column_name(1).as_deref(), Some("class"));
+    assert_eq!(rat.column_name(3), None);
+    assert_eq!(rat.column_type(0).unwrap(), RatFieldType::Integer);
+    assert_eq!(rat.column_type(2).unwrap(), RatFieldType::Real);
+    assert_eq!(rat.column_usage(1).unwrap(), RatFieldUsage::Name);
+    assert_eq!(rat.column_of_usage(RatFieldUsage::Name), Some(1));
+    assert_eq!(rat.column_of_usage(RatFieldUsage::Red), None);
+
+    assert_eq!(rat.get_value_as_string(1, 1).unwrap(), "water");
+    assert_eq!(rat.get_value_as_int(2, 0).unwrap(), 3);
+    assert_eq!(rat.get_value_as_double(0, 2).unwrap(), 1.5);
+    // values are converted to the requested type
+    assert_eq!(rat.get_value_as_string(2, 0).unwrap(), "3");
+    assert_eq!(rat.row_of_value(2.0), Some(1));
+    assert!(rat.get_value_as_int(3, 0).is_err());
+}
+
 #[test]
 fn test_create_color_table() {
     let outfile = TempFixture::empty("color_labels.tif");
@@ -808,6 +1143,37 @@ fn test_raster_stats() {
     );
 }
 
+#[test]
+fn test_compute_raster_min_max_known_range() -> Result<()> {
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let dataset = driver.create("", 20, 10, 1)?;
+    let mut band = dataset.rasterband(1)?;
+    let data = (0..200).map(|i| (i % 190 + 7) as u8).collect();
+    band.write((0, 0), (20, 10), &mut ByteBuffer::new((20, 10), data))?;
+
+    assert_eq!(
+        band.compute_raster_min_max(false)?,
+        StatisticsMinMax {
+            min: 7.0,
+            max: 196.0,
+        }
+    );
+
+    // nodata values are excluded from the range
+    band.set_no_data_value(Some(7.0))?;
+    assert_eq!(band.compute_raster_min_max(false)?.min, 8.0);
+
+    #[cfg(any(all(major_ge_3, minor_ge_6), major_ge_4))]
+    {
+        let _nolog = SuppressGDALErrorLog::new();
+        let dataset = driver.create("", 4, 4, 1)?;
+        let mut band = dataset.rasterband(1)?;
+        band.set_no_data_value(Some(0.0))?;
+        assert!(band.compute_raster_min_max(false).is_err());
+    }
+    Ok(())
+}
+
 #[test]
 fn test_raster_get_histogram() {
     let fixture = TempFixture::fixture("tinymarble.tif");
@@ -4,9 +4,10 @@ use std::{
 };
 
 use gdal_sys::{
-    GDALAdjustValueToDataType, GDALDataType, GDALDataTypeIsConversionLossy, GDALDataTypeIsFloating,
-    GDALDataTypeIsInteger, GDALDataTypeIsSigned, GDALDataTypeUnion, GDALFindDataTypeForValue,
-    GDALGetDataTypeByName, GDALGetDataTypeName, GDALGetDataTypeSizeBits, GDALGetDataTypeSizeBytes,
+    GDALAdjustValueToDataType, GDALDataType, GDALDataTypeIsComplex, GDALDataTypeIsConversionLossy,
+    GDALDataTypeIsFloating, GDALDataTypeIsInteger, GDALDataTypeIsSigned, GDALDataTypeUnion,
+    GDALFindDataTypeForValue, GDALGetDataTypeByName, GDALGetDataTypeName, GDALGetDataTypeSizeBits,
+    GDALGetDataTypeSizeBytes,
 };
 
 use crate::errors::{GdalError, Result};
@@ -16,6 +17,8 @@ use crate::utils::_string;
 ///
 /// A [`GDALDataType`] indicates the primitive storage value of a cell/pixel in a [`RasterBand`][crate::raster::RasterBand].
 ///
+/// The complex types (`GDT_CInt16` to `GDT_CFloat64`) have no variant, and convert to an error.
+///
 /// # Example
 /// ```rust, no_run
 /// use gdal::raster::GdalType;
@@ -44,10 +47,10 @@ pub enum GdalDataType {
     UInt32 = GDALDataType::GDT_UInt32,
     /// Thirty two bit signed integer
     Int32 = GDALDataType::GDT_Int32,
-    #[cfg(all(major_ge_3, minor_ge_5))]
+    #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
     /// 64 bit unsigned integer (GDAL >= 3.5)
     UInt64 = GDALDataType::GDT_UInt64,
-    #[cfg(all(major_ge_3, minor_ge_5))]
+    #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
     /// 64 bit signed integer  (GDAL >= 3.5)
     Int64 = GDALDataType::GDT_Int64,
     /// Thirty two bit floating point
@@ -120,6 +123,11 @@ impl GdalDataType {
             .expect("GDALGetDataTypeSizeBytes")
     }
 
+    /// Get the [`GDALDataType`] size in **bytes**. Same as [`bytes`][Self::bytes].
+    pub fn size_bytes(&self) -> u8 {
+        self.bytes()
+    }
+
     /// Returns `true` if [`GDALDataType`] is integral (non-floating point)
     pub fn is_integer(&self) -> bool {
         (unsafe { GDALDataTypeIsInteger(self.gdal_ordinal()) }) > 0
@@ -130,6 +138,14 @@ impl GdalDataType {
         (unsafe { GDALDataTypeIsFloating(self.gdal_ordinal()) }) > 0
     }
 
+    /// Returns `true` if [`GDALDataType`] is complex.
+    ///
+    /// As the complex types have no [`GdalDataType`] variant, this is currently `false` for
+    /// every value.
+    pub fn is_complex(&self) -> bool {
+        (unsafe { GDALDataTypeIsComplex(self.gdal_ordinal()) }) > 0
+    }
+
     /// Returns `true` if [`GDALDataType`] supports negative values.
     pub fn is_signed(&self) -> bool {
         (unsafe { GDALDataTypeIsSigned(self.gdal_ordinal()) }) > 0
@@ -212,9 +228,9 @@ impl GdalDataType {
             Int16,
             UInt32,
             Int32,
-            #[cfg(all(major_ge_3, minor_ge_5))]
+            #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
             UInt64,
-            #[cfg(all(major_ge_3, minor_ge_5))]
+            #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
             Int64,
             Float32,
             Float64,
@@ -271,9 +287,9 @@ impl TryFrom<u32> for GdalDataType {
             GDT_Int16 => Ok(GdalDataType::Int16),
             GDT_UInt32 => Ok(GdalDataType::UInt32),
             GDT_Int32 => Ok(GdalDataType::Int32),
-            #[cfg(all(major_ge_3, minor_ge_5))]
+            #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
             GDT_UInt64 => Ok(GdalDataType::UInt64),
-            #[cfg(all(major_ge_3, minor_ge_5))]
+            #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
             GDT_Int64 => Ok(GdalDataType::Int64),
             GDT_Float32 => Ok(GdalDataType::Float32),
             GDT_Float64 => Ok(GdalDataType::Float64),
@@ -365,7 +381,7 @@ impl GdalType for u32 {
     }
 }
 
-#[cfg(all(major_ge_3, minor_ge_5))]
+#[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
 /// Provides evidence `u64` is a valid [`GDALDataType`].
 impl GdalType for u64 {
     fn gdal_ordinal() -> GDALDataType::Type {
@@ -387,7 +403,7 @@ impl GdalType for i32 {
     }
 }
 
-#[cfg(all(major_ge_3, minor_ge_5))]
+#[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
 /// Provides evidence `i64` is a valid [`GDALDataType`].
 impl GdalType for i64 {
     fn gdal_ordinal() -> GDALDataType::Type {
@@ -424,6 +440,8 @@ mod tests {
             assert_eq!(&t, &t2, "{t}");
             assert!(t.bits() > 0, "{}", t);
             assert_eq!(t.bits(), t.bytes() * 8, "{t}");
+            assert_eq!(t.size_bytes(), t.bytes(), "{t}");
+            assert!(!t.is_complex(), "{t}");
             let name = t.name();
             match t.gdal_ordinal() {
                 GDT_Byte | GDT_UInt16 | GDT_Int16 | GDT_UInt32 | GDT_Int32 => {
@@ -435,7 +453,7 @@ mod tests {
                     assert!(t.is_integer(), "{}", &name);
                     assert!(!t.is_floating(), "{}", &name);
                 }
-                #[cfg(all(major_ge_3, minor_ge_5))]
+                #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
                 GDT_UInt64 | GDT_Int64 => {
                     assert!(t.is_integer(), "{}", &name);
                     assert!(!t.is_floating(), "{}", &name);
@@ -451,7 +469,7 @@ mod tests {
                 GDT_Byte | GDT_UInt16 | GDT_UInt32 => {
                     assert!(!t.is_signed(), "{}", &name);
                 }
-                #[cfg(all(major_ge_3, minor_ge_5))]
+                #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
                 GDT_UInt64 => {
                     assert!(!t.is_signed(), "{}", &name);
                 }
@@ -462,7 +480,7 @@ mod tests {
                 GDT_Int16 | GDT_Int32 | GDT_Float32 | GDT_Float64 => {
                     assert!(t.is_signed(), "{}", &name);
                 }
-                #[cfg(all(major_ge_3, minor_ge_5))]
+                #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
                 GDT_Int64 => {
                     assert!(t.is_signed(), "{}", &name);
                 }
@@ -505,7 +523,7 @@ mod tests {
         assert_eq!(u8d.union(u16d), u16d);
         assert_eq!(f32d.union(i32d), f64d);
 
-        #[cfg(all(major_ge_3, minor_ge_5))]
+        #[cfg(any(all(major_ge_3, minor_ge_5), major_ge_4))]
         {
             let u32d = <u32>::datatype();
             let i64d = <i64>::datatype();
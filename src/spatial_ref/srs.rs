@@ -6,6 +6,7 @@ use std::{
 
 use gdal_sys::{OGRErr, OSRAxisMappingStrategy};
 
+use crate::cpl::CslStringList;
 use crate::errors::*;
 use crate::utils::{_last_null_pointer_err, _string};
 
@@ -76,21 +77,21 @@ impl SpatialRef {
     ///
     /// [CPP]: https://gdal.org/api/ogrspatialref.html#_CPPv4N19OGRSpatialReference16SetFromUserInputEPKc
     pub fn from_definition(definition: &str) -> Result<SpatialRef> {
-        let c_obj = unsafe { gdal_sys::OSRNewSpatialReference(ptr::null()) };
-        if c_obj.is_null() {
-            return Err(_last_null_pointer_err("OSRNewSpatialReference"));
-        }
-        let rv =
-            unsafe { gdal_sys::OSRSetFromUserInput(c_obj, CString::new(definition)?.as_ptr()) };
+        let spatial_ref = Self::new()?;
+        let c_str = CString::new(definition)?;
+        let rv = unsafe { gdal_sys::OSRSetFromUserInput(spatial_ref.0, c_str.as_ptr()) };
         if rv != OGRErr::OGRERR_NONE {
             return Err(GdalError::OgrError {
                 err: rv,
                 method_name: "OSRSetFromUserInput",
             });
         }
-        Ok(SpatialRef(c_obj))
+        Ok(spatial_ref)
     }
 
+    /// Create a spatial reference from a WKT (version 1 or 2) string.
+    ///
+    /// See: [`OSRNewSpatialReference`](https://gdal.org/api/ogr_srs_api.html#_CPPv422OSRNewSpatialReferencePKc)
     pub fn from_wkt(wkt: &str) -> Result<SpatialRef> {
         let c_str = CString::new(wkt)?;
         let c_obj = unsafe { gdal_sys::OSRNewSpatialReference(c_str.as_ptr()) };
@@ -100,48 +101,72 @@ impl SpatialRef {
         Ok(SpatialRef(c_obj))
     }
 
+    /// Create a spatial reference from an EPSG code.
+    ///
+    /// See: [`OSRImportFromEPSG`](https://gdal.org/api/ogr_srs_api.html#_CPPv417OSRImportFromEPSG20OGRSpatialReferenceHi)
     pub fn from_epsg(epsg_code: u32) -> Result<SpatialRef> {
-        let null_ptr = ptr::null_mut();
-        let c_obj = unsafe { gdal_sys::OSRNewSpatialReference(null_ptr) };
-        let rv = unsafe { gdal_sys::OSRImportFromEPSG(c_obj, epsg_code as c_int) };
+        let spatial_ref = Self::new()?;
+        let rv = unsafe { gdal_sys::OSRImportFromEPSG(spatial_ref.0, epsg_code as c_int) };
         if rv != OGRErr::OGRERR_NONE {
             Err(GdalError::OgrError {
                 err: rv,
                 method_name: "OSRImportFromEPSG",
             })
         } else {
-            Ok(SpatialRef(c_obj))
+            Ok(spatial_ref)
         }
     }
 
+    /// Create a spatial reference from a PROJ.4 definition string.
+    ///
+    /// See: [`OSRImportFromProj4`](https://gdal.org/api/ogr_srs_api.html#_CPPv418OSRImportFromProj420OGRSpatialReferenceHPKc)
     pub fn from_proj4(proj4_string: &str) -> Result<SpatialRef> {
         let c_str = CString::new(proj4_string)?;
-        let null_ptr = ptr::null_mut();
-        let c_obj = unsafe { gdal_sys::OSRNewSpatialReference(null_ptr) };
-        let rv = unsafe { gdal_sys::OSRImportFromProj4(c_obj, c_str.as_ptr()) };
+        let spatial_ref = Self::new()?;
+        let rv = unsafe { gdal_sys::OSRImportFromProj4(spatial_ref.0, c_str.as_ptr()) };
         if rv != OGRErr::OGRERR_NONE {
             Err(GdalError::OgrError {
                 err: rv,
                 method_name: "OSRImportFromProj4",
             })
         } else {
-            Ok(SpatialRef(c_obj))
+            Ok(spatial_ref)
+        }
+    }
+
+    /// Create a spatial reference from a [PROJJSON] document, as produced by
+    /// [`to_projjson`][Self::to_projjson].
+    ///
+    /// Returns an error if `projjson` is not a JSON object.
+    ///
+    /// See: [`OSRSetFromUserInput`](https://gdal.org/api/ogr_srs_api.html#_CPPv419OSRSetFromUserInput20OGRSpatialReferenceHPKc)
+    ///
+    /// [PROJJSON]: https://proj.org/specifications/projjson.html
+    pub fn from_projjson(projjson: &str) -> Result<SpatialRef> {
+        if !projjson.trim_start().starts_with('{') {
+            return Err(GdalError::BadArgument(
+                "PROJJSON must be a JSON object".to_string(),
+            ));
         }
+        Self::from_definition(projjson)
     }
 
+    /// Create a spatial reference from an ESRI-flavoured WKT string, such as the
+    /// contents of a `.prj` file.
+    ///
+    /// See: [`OSRImportFromESRI`](https://gdal.org/api/ogr_srs_api.html#_CPPv417OSRImportFromESRI20OGRSpatialReferenceHPPc)
     pub fn from_esri(esri_wkt: &str) -> Result<SpatialRef> {
         let c_str = CString::new(esri_wkt)?;
         let mut ptrs = vec![c_str.as_ptr() as *mut c_char, ptr::null_mut()];
-        let null_ptr = ptr::null_mut();
-        let c_obj = unsafe { gdal_sys::OSRNewSpatialReference(null_ptr) };
-        let rv = unsafe { gdal_sys::OSRImportFromESRI(c_obj, ptrs.as_mut_ptr()) };
+        let spatial_ref = Self::new()?;
+        let rv = unsafe { gdal_sys::OSRImportFromESRI(spatial_ref.0, ptrs.as_mut_ptr()) };
         if rv != OGRErr::OGRERR_NONE {
             Err(GdalError::OgrError {
                 err: rv,
                 method_name: "OSRImportFromESRI",
             })
         } else {
-            Ok(SpatialRef(c_obj))
+            Ok(spatial_ref)
         }
     }
 
@@ -160,6 +185,54 @@ impl SpatialRef {
         res
     }
 
+    /// Export this spatial reference to WKT in the given dialect.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::spatial_ref::{SpatialRef, WktFormat};
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let srs = SpatialRef::from_epsg(4326)?;
+    /// let wkt2 = srs.to_wkt_formatted(WktFormat::Wkt2_2019)?;
+    /// assert!(wkt2.starts_with("GEOGCRS["));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// See: [`OSRExportToWktEx`](https://gdal.org/api/ogr_srs_api.html#_CPPv416OSRExportToWktEx20OGRSpatialReferenceHPPcPPCKc)
+    pub fn to_wkt_formatted(&self, format: WktFormat) -> Result<String> {
+        self._to_wkt_ex(format, false)
+    }
+
+    /// Export this spatial reference to WKT in the given dialect, over multiple indented lines.
+    ///
+    /// See [`to_wkt_formatted`][Self::to_wkt_formatted] for the single line version.
+    ///
+    /// See: [`OSRExportToWktEx`](https://gdal.org/api/ogr_srs_api.html#_CPPv416OSRExportToWktEx20OGRSpatialReferenceHPPcPPCKc)
+    pub fn to_pretty_wkt_formatted(&self, format: WktFormat) -> Result<String> {
+        self._to_wkt_ex(format, true)
+    }
+
+    fn _to_wkt_ex(&self, format: WktFormat, multiline: bool) -> Result<String> {
+        let mut options = CslStringList::new();
+        options.set_name_value("FORMAT", format.to_gdal_option())?;
+        options.set_name_value("MULTILINE", if multiline { "YES" } else { "NO" })?;
+        let mut c_wkt = ptr::null_mut();
+        let rv = unsafe {
+            gdal_sys::OSRExportToWktEx(self.0, &mut c_wkt, options.as_ptr() as *const *const c_char)
+        };
+        let res = if rv != OGRErr::OGRERR_NONE {
+            Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OSRExportToWktEx",
+            })
+        } else {
+            Ok(_string(c_wkt).unwrap_or_default())
+        };
+        unsafe { gdal_sys::VSIFree(c_wkt.cast::<std::ffi::c_void>()) };
+        res
+    }
+
     pub fn morph_to_esri(&self) -> Result<()> {
         let rv = unsafe { gdal_sys::OSRMorphToESRI(self.0) };
         if rv != OGRErr::OGRERR_NONE {
@@ -201,6 +274,13 @@ impl SpatialRef {
         res
     }
 
+    /// Export this spatial reference to a PROJ.4 definition string.
+    ///
+    /// PROJ strings can't represent every CRS: names, authority codes and some datum
+    /// information are lost. Prefer [`to_projjson`][Self::to_projjson] or
+    /// [`to_wkt_formatted`][Self::to_wkt_formatted] for lossless exchange.
+    ///
+    /// See: [`OSRExportToProj4`](https://gdal.org/api/ogr_srs_api.html#_CPPv416OSRExportToProj420OGRSpatialReferenceHPPc)
     pub fn to_proj4(&self) -> Result<String> {
         let mut c_proj4str = ptr::null_mut();
         let rv = unsafe { gdal_sys::OSRExportToProj4(self.0, &mut c_proj4str) };
@@ -216,6 +296,11 @@ impl SpatialRef {
         res
     }
 
+    /// Export this spatial reference to a [PROJJSON] document.
+    ///
+    /// See: [`OSRExportToPROJJSON`](https://gdal.org/api/ogr_srs_api.html#_CPPv419OSRExportToPROJJSON20OGRSpatialReferenceHPPcPPCKc)
+    ///
+    /// [PROJJSON]: https://proj.org/specifications/projjson.html
     pub fn to_projjson(&self) -> Result<String> {
         let mut c_projjsonstr = ptr::null_mut();
         let options = ptr::null();
@@ -232,11 +317,21 @@ impl SpatialRef {
         res
     }
 
+    /// Returns the name of the authority that defines this SRS, e.g. `"EPSG"`,
+    /// or `None` if the SRS has no authority.
+    ///
+    /// See: [`OSRGetAuthorityName`](https://gdal.org/api/ogr_srs_api.html#_CPPv419OSRGetAuthorityName20OGRSpatialReferenceHPKc)
     pub fn auth_name(&self) -> Option<String> {
         let c_ptr = unsafe { gdal_sys::OSRGetAuthorityName(self.0, ptr::null()) };
         _string(c_ptr)
     }
 
+    /// Returns the numeric authority code of this SRS, e.g. `4326`.
+    ///
+    /// Fails if the SRS has no authority code, or if the code isn't numeric (like `OGC:CRS84`);
+    /// use [`authority`][Self::authority] to get the code as text.
+    ///
+    /// See: [`OSRGetAuthorityCode`](https://gdal.org/api/ogr_srs_api.html#_CPPv419OSRGetAuthorityCode20OGRSpatialReferenceHPKc)
     pub fn auth_code(&self) -> Result<i32> {
         // FIXME: this should actually return a string
 
@@ -257,6 +352,32 @@ impl SpatialRef {
         }
     }
 
+    /// Returns the name of the authority that defines the `target_key` element of this
+    /// SRS, such as `"GEOGCS"`, `"DATUM"` or `"SPHEROID"`.
+    ///
+    /// Returns `None` if there is no such element, or if it has no authority.
+    ///
+    /// See: [`OSRGetAuthorityName`](https://gdal.org/api/ogr_srs_api.html#_CPPv419OSRGetAuthorityName20OGRSpatialReferenceHPKc)
+    pub fn auth_name_for(&self, target_key: &str) -> Result<Option<String>> {
+        let c_target_key = CString::new(target_key)?;
+        let c_ptr = unsafe { gdal_sys::OSRGetAuthorityName(self.0, c_target_key.as_ptr()) };
+        Ok(_string(c_ptr))
+    }
+
+    /// Returns the authority code of the `target_key` element of this SRS, such as
+    /// `"GEOGCS"`, `"DATUM"` or `"SPHEROID"`, e.g. `"4326"` for the `"GEOGCS"` of `EPSG:3857`.
+    ///
+    /// Returns `None` if there is no such element, or if it has no authority.
+    ///
+    /// See: [`OSRGetAuthorityCode`](https://gdal.org/api/ogr_srs_api.html#_CPPv419OSRGetAuthorityCode20OGRSpatialReferenceHPKc)
+    pub fn auth_code_for(&self, target_key: &str) -> Result<Option<String>> {
+        let c_target_key = CString::new(target_key)?;
+        let c_ptr = unsafe { gdal_sys::OSRGetAuthorityCode(self.0, c_target_key.as_ptr()) };
+        Ok(_string(c_ptr))
+    }
+
+    /// Returns the authority name and code of this SRS, formatted as `"NAME:CODE"`,
+    /// e.g. `"EPSG:4326"`.
     pub fn authority(&self) -> Result<String> {
         let c_ptr = unsafe { gdal_sys::OSRGetAuthorityName(self.0, ptr::null()) };
         if c_ptr.is_null() {
@@ -271,6 +392,14 @@ impl SpatialRef {
         Ok(format!("{name}:{code}"))
     }
 
+    /// Try to set the EPSG authority code of this SRS, if it is missing.
+    ///
+    /// This recognizes common geographic systems, UTM zones and a few other well known
+    /// projections from their definition. Custom projections, and systems it does not
+    /// know about, fail with [`OGRErr::OGRERR_UNSUPPORTED_SRS`]; see
+    /// [`find_matches`][Self::find_matches] for a more thorough search.
+    ///
+    /// See: [`OSRAutoIdentifyEPSG`](https://gdal.org/api/ogr_srs_api.html#_CPPv419OSRAutoIdentifyEPSG20OGRSpatialReferenceH)
     pub fn auto_identify_epsg(&mut self) -> Result<()> {
         let rv = unsafe { gdal_sys::OSRAutoIdentifyEPSG(self.0) };
         if rv != OGRErr::OGRERR_NONE {
@@ -283,6 +412,48 @@ impl SpatialRef {
         }
     }
 
+    /// Search the PROJ database for systems matching this SRS.
+    ///
+    /// Returns the candidates along with a confidence between `0` and `100`, the best
+    /// matches first. A confidence of `100` means the definitions are equivalent;
+    /// anything lower, e.g. when the names differ, should be checked before use.
+    /// Custom projections usually give no match at all.
+    ///
+    /// See: [`OSRFindMatches`](https://gdal.org/api/ogr_srs_api.html#_CPPv414OSRFindMatches20OGRSpatialReferenceHPPcPiPPi)
+    pub fn find_matches(&self) -> Result<Vec<(SpatialRef, i32)>> {
+        let mut n_entries: c_int = 0;
+        let mut c_confidences: *mut c_int = ptr::null_mut();
+        let c_matches = unsafe {
+            gdal_sys::OSRFindMatches(self.0, ptr::null_mut(), &mut n_entries, &mut c_confidences)
+        };
+        if c_matches.is_null() {
+            return Ok(Vec::new());
+        }
+
+        let mut matches = Vec::with_capacity(n_entries as usize);
+        for i in 0..n_entries as usize {
+            let (c_match, confidence) = unsafe { (*c_matches.add(i), *c_confidences.add(i)) };
+            match unsafe { SpatialRef::from_c_obj(c_match) } {
+                Ok(spatial_ref) => matches.push((spatial_ref, confidence)),
+                Err(e) => {
+                    unsafe {
+                        gdal_sys::OSRFreeSRSArray(c_matches);
+                        gdal_sys::VSIFree(c_confidences.cast());
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        unsafe {
+            gdal_sys::OSRFreeSRSArray(c_matches);
+            gdal_sys::VSIFree(c_confidences.cast());
+        }
+        Ok(matches)
+    }
+
+    /// Returns the name of this SRS, e.g. `"WGS 84 / Pseudo-Mercator"`.
+    ///
+    /// See: [`OSRGetName`](https://gdal.org/api/ogr_srs_api.html#_CPPv410OSRGetName20OGRSpatialReferenceH)
     pub fn name(&self) -> Option<String> {
         let c_ptr = unsafe { gdal_sys::OSRGetName(self.0) };
         _string(c_ptr)
@@ -298,12 +469,19 @@ impl SpatialRef {
         unsafe { gdal_sys::OSRGetAngularUnits(self.0, ptr::null_mut()) }
     }
 
+    /// Returns the name of the linear units of this SRS, e.g. `"metre"`.
+    ///
+    /// See: [`OSRGetLinearUnits`](https://gdal.org/api/ogr_srs_api.html#_CPPv417OSRGetLinearUnits20OGRSpatialReferenceHPPc)
     pub fn linear_units_name(&self) -> Option<String> {
         let mut c_ptr = ptr::null_mut();
         unsafe { gdal_sys::OSRGetLinearUnits(self.0, &mut c_ptr) };
         _string(c_ptr)
     }
 
+    /// Returns the multiplier converting the linear units of this SRS to meters,
+    /// e.g. `0.3048` for feet.
+    ///
+    /// See: [`OSRGetLinearUnits`](https://gdal.org/api/ogr_srs_api.html#_CPPv417OSRGetLinearUnits20OGRSpatialReferenceHPPc)
     pub fn linear_units(&self) -> f64 {
         unsafe { gdal_sys::OSRGetLinearUnits(self.0, ptr::null_mut()) }
     }
@@ -343,6 +521,17 @@ impl SpatialRef {
         unsafe { gdal_sys::OSRIsVertical(self.0) == 1 }
     }
 
+    /// Returns `true` if the geographic coordinate systems of `self` and `other` are
+    /// equivalent, e.g. for a projected CRS and the geographic CRS it is based on.
+    ///
+    /// Like the [`PartialEq`] implementation, this compares the definitions semantically,
+    /// so differences in WKT formatting or authority codes don't matter.
+    ///
+    /// See: [`OSRIsSameGeogCS`](https://gdal.org/api/ogr_srs_api.html#_CPPv415OSRIsSameGeogCS20OGRSpatialReferenceH20OGRSpatialReferenceH)
+    pub fn is_same_geogcs(&self, other: &SpatialRef) -> bool {
+        unsafe { gdal_sys::OSRIsSameGeogCS(self.0, other.0) == 1 }
+    }
+
     pub fn axis_orientation(
         &self,
         target_key: &str,
@@ -413,6 +602,65 @@ impl SpatialRef {
         id.try_into().expect("valid enumeration ordinal from GDAL")
     }
 
+    /// Returns the mapping from data axes to the axes of this CRS.
+    ///
+    /// Entry `i` is the 1-based index of the CRS axis that data axis `i` maps to, negated
+    /// when the axis direction is flipped. For example, EPSG:4326 is `[1, 2]` (lat/long)
+    /// with [`AxisMappingStrategy::AuthorityCompliant`], and `[2, 1]` with
+    /// [`AxisMappingStrategy::TraditionalGisOrder`].
+    ///
+    /// See: [`OSRGetDataAxisToSRSAxisMapping`](https://gdal.org/api/ogr_srs_api.html#_CPPv430OSRGetDataAxisToSRSAxisMapping20OGRSpatialReferenceHPi)
+    pub fn data_axis_to_srs_axis_mapping(&self) -> Vec<i32> {
+        let mut count: c_int = 0;
+        let c_mapping = unsafe { gdal_sys::OSRGetDataAxisToSRSAxisMapping(self.0, &mut count) };
+        if c_mapping.is_null() || count <= 0 {
+            return Vec::new();
+        }
+        unsafe { std::slice::from_raw_parts(c_mapping, count as usize) }.to_vec()
+    }
+
+    /// Set a custom mapping from data axes to the axes of this CRS, see
+    /// [`data_axis_to_srs_axis_mapping`][Self::data_axis_to_srs_axis_mapping].
+    ///
+    /// This switches the strategy to [`AxisMappingStrategy::Custom`].
+    ///
+    /// See: [`OSRSetDataAxisToSRSAxisMapping`](https://gdal.org/api/ogr_srs_api.html#_CPPv430OSRSetDataAxisToSRSAxisMapping20OGRSpatialReferenceHiPKi)
+    pub fn set_data_axis_to_srs_axis_mapping(&mut self, mapping: &[i32]) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::OSRSetDataAxisToSRSAxisMapping(
+                self.0,
+                mapping.len().try_into()?,
+                mapping.as_ptr(),
+            )
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OSRSetDataAxisToSRSAxisMapping",
+            });
+        }
+        Ok(())
+    }
+
+    /// Set the coordinate epoch of this `SpatialRef`, as a decimal year (e.g. `2020.5`).
+    ///
+    /// Only meaningful for dynamic CRS, such as the ITRF realizations, whose coordinates
+    /// drift over time. The epoch is used by time-dependent coordinate transformations.
+    ///
+    /// See: [`OSRSetCoordinateEpoch`](https://gdal.org/api/ogr_srs_api.html#_CPPv421OSRSetCoordinateEpoch20OGRSpatialReferenceHd)
+    pub fn set_coordinate_epoch(&mut self, epoch: f64) {
+        unsafe { gdal_sys::OSRSetCoordinateEpoch(self.0, epoch) };
+    }
+
+    /// Get the coordinate epoch of this `SpatialRef`, as a decimal year,
+    /// or `None` if it isn't set.
+    ///
+    /// See: [`OSRGetCoordinateEpoch`](https://gdal.org/api/ogr_srs_api.html#_CPPv421OSRGetCoordinateEpoch20OGRSpatialReferenceH)
+    pub fn coordinate_epoch(&self) -> Option<f64> {
+        let epoch = unsafe { gdal_sys::OSRGetCoordinateEpoch(self.0) };
+        (epoch != 0.0).then_some(epoch)
+    }
+
     /// Get the valid use bounding area for this `SpatialRef`.
     ///
     /// See: [`OSRGetAreaOfUse`](https://gdal.org/api/ogr_srs_api.html#_CPPv415OSRGetAreaOfUse20OGRSpatialReferenceHPdPdPdPdPPKc)
@@ -478,6 +726,23 @@ impl SpatialRef {
         Ok(b)
     }
 
+    /// Get spheroid inverse flattening.
+    ///
+    /// Returns an error if the inverse flattening can't be found
+    ///
+    /// See: [`OSRGetInvFlattening`](https://gdal.org/api/ogr_srs_api.html#_CPPv419OSRGetInvFlattening20OGRSpatialReferenceHP6OGRErr)
+    pub fn inv_flattening(&self) -> Result<f64> {
+        let mut err_code = OGRErr::OGRERR_NONE;
+        let f = unsafe { gdal_sys::OSRGetInvFlattening(self.0, &mut err_code as *mut u32) };
+        if err_code != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: err_code,
+                method_name: "OSRGetInvFlattening",
+            });
+        }
+        Ok(f)
+    }
+
     /// Set a projection parameter value.
     ///
     /// Returns an error if there the `PROJCS` node is missing.
@@ -594,6 +859,42 @@ pub struct AreaOfUse {
     pub name: String,
 }
 
+/// WKT dialect used by [`SpatialRef::to_wkt_formatted`].
+///
+/// See: [`OSRExportToWktEx`](https://gdal.org/api/ogr_srs_api.html#_CPPv416OSRExportToWktEx20OGRSpatialReferenceHPPcPPCKc)
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum WktFormat {
+    /// WKT1 as in the OGC Simple Features for SQL specification.
+    SfSql,
+    /// WKT1 without `AXIS`, `TOWGS84`, `AUTHORITY` or `EXTENSION` nodes.
+    Wkt1Simple,
+    /// WKT1 as traditionally written by GDAL, the format of [`SpatialRef::to_wkt`].
+    Wkt1Gdal,
+    /// WKT1 as understood by ESRI software.
+    Wkt1Esri,
+    /// WKT2 as defined in ISO 19162:2015.
+    Wkt2_2015,
+    /// WKT2 as defined in ISO 19162:2019.
+    Wkt2_2019,
+    /// The most recent WKT2 version supported by GDAL.
+    Wkt2,
+}
+
+impl WktFormat {
+    fn to_gdal_option(self) -> &'static str {
+        match self {
+            WktFormat::SfSql => "SFSQL",
+            WktFormat::Wkt1Simple => "WKT1_SIMPLE",
+            WktFormat::Wkt1Gdal => "WKT1_GDAL",
+            WktFormat::Wkt1Esri => "WKT1_ESRI",
+            WktFormat::Wkt2_2015 => "WKT2_2015",
+            WktFormat::Wkt2_2019 => "WKT2_2019",
+            WktFormat::Wkt2 => "WKT2",
+        }
+    }
+}
+
 /// Data axis to CRS axis mapping strategy.
 ///
 /// See: [`OSRGetAxisMappingStrategy`](https://gdal.org/api/ogrspatialref.html#_CPPv4NK19OGRSpatialReference22GetAxisMappingStrategyEv)
@@ -606,7 +907,7 @@ pub enum AxisMappingStrategy {
     TraditionalGisOrder = OSRAxisMappingStrategy::OAMS_TRADITIONAL_GIS_ORDER,
     /// The data axis will be identical to the CRS axis.
     AuthorityCompliant = OSRAxisMappingStrategy::OAMS_AUTHORITY_COMPLIANT,
-    /// The data axes are custom-defined via [`gdal_sys::OSRSetDataAxisToSRSAxisMapping`].
+    /// The data axes are custom-defined via [`SpatialRef::set_data_axis_to_srs_axis_mapping`].
     Custom = OSRAxisMappingStrategy::OAMS_CUSTOM,
 }
 
@@ -638,6 +939,8 @@ impl TryFrom<u32> for AxisMappingStrategy {
 mod tests {
     use super::*;
     use crate::assert_almost_eq;
+    use crate::spatial_ref::CoordTransform;
+    use crate::test_utils::SuppressGDALErrorLog;
 
     #[test]
     fn from_wkt_to_proj4() {
@@ -698,6 +1001,113 @@ mod tests {
         assert_eq!("+proj=longlat +datum=WGS84 +no_defs", proj4string.trim());
     }
 
+    #[test]
+    fn from_definition_user_input() {
+        let from_code = SpatialRef::from_definition("EPSG:4326").unwrap();
+        assert!(from_code.to_wkt().unwrap().contains("GEOGCS[\"WGS 84\""));
+        assert_eq!(from_code, SpatialRef::from_epsg(4326).unwrap());
+
+        let from_urn = SpatialRef::from_definition("urn:ogc:def:crs:EPSG::3857").unwrap();
+        assert!(from_urn
+            .to_wkt()
+            .unwrap()
+            .contains("PROJCS[\"WGS 84 / Pseudo-Mercator\""));
+
+        let from_prj = SpatialRef::from_definition("GEOGCS[\"GCS_WGS_1984\",DATUM[\"D_WGS_1984\",SPHEROID[\"WGS_1984\",6378137,298.257223563]],PRIMEM[\"Greenwich\",0],UNIT[\"Degree\",0.017453292519943295]]").unwrap();
+        assert!(from_prj.to_wkt().unwrap().contains("SPHEROID[\"WGS"));
+    }
+
+    #[test]
+    fn import_errors() {
+        let _nolog = SuppressGDALErrorLog::new();
+        assert!(matches!(
+            SpatialRef::from_epsg(999999),
+            Err(GdalError::OgrError {
+                method_name: "OSRImportFromEPSG",
+                ..
+            })
+        ));
+        assert!(matches!(
+            SpatialRef::from_proj4("+proj=not_a_projection"),
+            Err(GdalError::OgrError {
+                method_name: "OSRImportFromProj4",
+                ..
+            })
+        ));
+        assert!(matches!(
+            SpatialRef::from_definition("not a crs"),
+            Err(GdalError::OgrError {
+                method_name: "OSRSetFromUserInput",
+                ..
+            })
+        ));
+        assert!(SpatialRef::from_wkt("GEOGCS[").is_err());
+    }
+
+    #[test]
+    fn to_wkt_formatted() {
+        let spatial_ref = SpatialRef::from_epsg(4326).unwrap();
+        assert_eq!(
+            spatial_ref.to_wkt_formatted(WktFormat::Wkt1Gdal).unwrap(),
+            spatial_ref.to_wkt().unwrap()
+        );
+        let esri = spatial_ref.to_wkt_formatted(WktFormat::Wkt1Esri).unwrap();
+        assert!(esri.starts_with("GEOGCS[\"GCS_WGS_1984\""));
+        let simple = spatial_ref.to_wkt_formatted(WktFormat::Wkt1Simple).unwrap();
+        assert!(!simple.contains("AUTHORITY"));
+    }
+
+    #[test]
+    fn to_wkt2_versions() {
+        let spatial_ref = SpatialRef::from_epsg(4326).unwrap();
+        let wkt2_2015 = spatial_ref.to_wkt_formatted(WktFormat::Wkt2_2015).unwrap();
+        assert!(wkt2_2015.starts_with("GEODCRS[\"WGS 84\""));
+        assert!(!wkt2_2015.contains("USAGE["));
+        let wkt2_2019 = spatial_ref.to_wkt_formatted(WktFormat::Wkt2_2019).unwrap();
+        assert!(wkt2_2019.starts_with("GEOGCRS[\"WGS 84\""));
+        assert!(wkt2_2019.contains("USAGE["));
+        assert!(!wkt2_2019.contains('\n'));
+
+        let pretty = spatial_ref
+            .to_pretty_wkt_formatted(WktFormat::Wkt2_2019)
+            .unwrap();
+        assert!(pretty.lines().count() > 1);
+        assert!(pretty.lines().nth(1).unwrap().starts_with("    "));
+        assert_eq!(
+            SpatialRef::from_wkt(&pretty).unwrap(),
+            SpatialRef::from_wkt(&wkt2_2019).unwrap()
+        );
+    }
+
+    #[test]
+    fn projjson_round_trip() {
+        let spatial_ref = SpatialRef::from_epsg(32632).unwrap();
+        let projjson = spatial_ref.to_projjson().unwrap();
+        assert!(projjson.contains("\"type\": \"ProjectedCRS\""));
+
+        let round_trip = SpatialRef::from_projjson(&projjson).unwrap();
+        assert_eq!(round_trip, spatial_ref);
+        assert_eq!(round_trip.auth_code().unwrap(), 32632);
+        assert_eq!(round_trip.name().unwrap(), "WGS 84 / UTM zone 32N");
+
+        assert!(matches!(
+            SpatialRef::from_projjson("EPSG:32632"),
+            Err(GdalError::BadArgument(_))
+        ));
+    }
+
+    #[test]
+    fn wkt2_round_trip() {
+        let spatial_ref = SpatialRef::from_epsg(32632).unwrap();
+        let wkt2 = spatial_ref.to_wkt_formatted(WktFormat::Wkt2_2019).unwrap();
+        assert!(wkt2.starts_with("PROJCRS[\"WGS 84 / UTM zone 32N\""));
+        assert!(wkt2.contains("ID[\"EPSG\",32632]"));
+
+        let round_trip = SpatialRef::from_wkt(&wkt2).unwrap();
+        assert_eq!(round_trip, spatial_ref);
+        assert_eq!(round_trip.auth_code().unwrap(), 32632);
+    }
+
     #[test]
     fn comparison() {
         let spatial_ref1 = SpatialRef::from_wkt("GEOGCS[\"WGS 84\",DATUM[\"WGS_1984\",SPHEROID[\"WGS 84\",6378137,298.257223563,AUTHORITY[\"EPSG\",7030]],TOWGS84[0,0,0,0,0,0,0],AUTHORITY[\"EPSG\",6326]],PRIMEM[\"Greenwich\",0,AUTHORITY[\"EPSG\",8901]],UNIT[\"DMSH\",0.0174532925199433,AUTHORITY[\"EPSG\",9108]],AXIS[\"Lat\",NORTH],AXIS[\"Long\",EAST],AUTHORITY[\"EPSG\",4326]]").unwrap();
@@ -712,6 +1122,23 @@ mod tests {
         assert_eq!(spatial_ref5, spatial_ref4);
     }
 
+    #[test]
+    fn comparison_with_wkt_and_geogcs() {
+        let wgs84 = SpatialRef::from_epsg(4326).unwrap();
+        let from_wkt = SpatialRef::from_wkt(&wgs84.to_pretty_wkt().unwrap()).unwrap();
+        let web_mercator = SpatialRef::from_epsg(3857).unwrap();
+        let utm = SpatialRef::from_epsg(32633).unwrap();
+        let nad27 = SpatialRef::from_epsg(4267).unwrap();
+
+        assert_eq!(wgs84, from_wkt);
+        assert_eq!(wgs84.clone(), wgs84);
+        assert_ne!(wgs84, web_mercator);
+
+        assert!(wgs84.is_same_geogcs(&from_wkt));
+        assert!(wgs84.is_same_geogcs(&utm));
+        assert!(!wgs84.is_same_geogcs(&nad27));
+    }
+
     #[test]
     fn authority() {
         let spatial_ref = SpatialRef::from_epsg(4326).unwrap();
@@ -735,6 +1162,15 @@ mod tests {
         assert!(spatial_ref.authority().is_err());
     }
 
+    #[test]
+    fn non_numeric_authority() {
+        let spatial_ref = SpatialRef::from_definition("OGC:CRS84").unwrap();
+        assert_eq!(spatial_ref.auth_name().as_deref(), Some("OGC"));
+        assert_eq!(spatial_ref.authority().unwrap(), "OGC:CRS84");
+        assert!(spatial_ref.auth_code().is_err());
+        assert_eq!(spatial_ref.name().as_deref(), Some("WGS 84 (CRS84)"));
+    }
+
     #[test]
     fn auto_identify() {
         // retrieved from https://epsg.io/32632, but deleted the `AUTHORITY["EPSG","32632"]`
@@ -769,6 +1205,35 @@ mod tests {
         assert_eq!(spatial_ref.auth_code().unwrap(), 32632);
     }
 
+    #[test]
+    fn find_matches() {
+        let spatial_ref =
+            SpatialRef::from_proj4("+proj=utm +zone=33 +datum=WGS84 +units=m +no_defs").unwrap();
+        let matches = spatial_ref.find_matches().unwrap();
+        let (best, confidence) = matches.first().unwrap();
+        assert_eq!(best.authority().unwrap(), "EPSG:32633");
+        assert!(*confidence >= 50);
+        assert!(matches.windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+
+    #[test]
+    fn identify_custom_projection() {
+        let mut spatial_ref = SpatialRef::from_proj4(
+            "+proj=tmerc +lat_0=12.3 +lon_0=45.6 +k=0.9 +x_0=123 +y_0=456 +ellps=WGS84 +units=m",
+        )
+        .unwrap();
+        let matches = spatial_ref.find_matches().unwrap();
+        assert!(matches.iter().all(|(_, confidence)| *confidence < 100));
+        let err = spatial_ref.auto_identify_epsg().unwrap_err();
+        assert!(matches!(
+            err,
+            GdalError::OgrError {
+                err: OGRErr::OGRERR_UNSUPPORTED_SRS,
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn axis_mapping_strategy() {
         let mut spatial_ref = SpatialRef::from_epsg(4326).unwrap();
@@ -783,6 +1248,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn data_axis_to_srs_axis_mapping() {
+        let mut spatial_ref = SpatialRef::from_epsg(4326).unwrap();
+        assert_eq!(spatial_ref.data_axis_to_srs_axis_mapping(), [1, 2]);
+        spatial_ref.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        assert_eq!(spatial_ref.data_axis_to_srs_axis_mapping(), [2, 1]);
+
+        let mut spatial_ref = SpatialRef::from_epsg(4326).unwrap();
+        spatial_ref
+            .set_data_axis_to_srs_axis_mapping(&[2, 1])
+            .unwrap();
+        assert_eq!(
+            spatial_ref.axis_mapping_strategy(),
+            AxisMappingStrategy::Custom
+        );
+
+        // the custom mapping swaps lat/long, like the traditional GIS order
+        let web_mercator = SpatialRef::from_epsg(3857).unwrap();
+        let transform = CoordTransform::new(&spatial_ref, &web_mercator).unwrap();
+        let (mut x, mut y, mut z) = ([10.0], [0.0], [0.0]);
+        transform.transform_coords(&mut x, &mut y, &mut z).unwrap();
+        assert_almost_eq(x[0], 1113194.9079327357);
+        assert_almost_eq(y[0], 0.0);
+    }
+
+    #[test]
+    fn coordinate_epoch() {
+        // ITRF2014
+        let mut spatial_ref = SpatialRef::from_epsg(9000).unwrap();
+        assert_eq!(spatial_ref.coordinate_epoch(), None);
+        spatial_ref.set_coordinate_epoch(2020.5);
+        assert_eq!(spatial_ref.coordinate_epoch(), Some(2020.5));
+        assert_eq!(spatial_ref.clone().coordinate_epoch(), Some(2020.5));
+    }
+
     #[test]
     fn area_of_use() {
         let spatial_ref = SpatialRef::from_epsg(4326).unwrap();
@@ -793,6 +1293,29 @@ mod tests {
         assert_almost_eq(area_of_use.north_lat_degree, 90.0);
     }
 
+    #[test]
+    fn authority_of_elements() {
+        let spatial_ref = SpatialRef::from_epsg(3857).unwrap();
+        assert_eq!(spatial_ref.auth_name().unwrap(), "EPSG");
+        assert_eq!(spatial_ref.auth_code().unwrap(), 3857);
+        assert_eq!(spatial_ref.authority().unwrap(), "EPSG:3857");
+        assert_eq!(spatial_ref.name().unwrap(), "WGS 84 / Pseudo-Mercator");
+
+        assert_eq!(
+            spatial_ref.auth_name_for("GEOGCS").unwrap().as_deref(),
+            Some("EPSG")
+        );
+        assert_eq!(
+            spatial_ref.auth_code_for("GEOGCS").unwrap().as_deref(),
+            Some("4326")
+        );
+        assert_eq!(
+            spatial_ref.auth_code_for("DATUM").unwrap().as_deref(),
+            Some("6326")
+        );
+        assert_eq!(spatial_ref.auth_code_for("BOGUS").unwrap(), None);
+    }
+
     #[test]
     fn get_name() {
         let spatial_ref = SpatialRef::from_epsg(4326).unwrap();
@@ -866,6 +1389,25 @@ mod tests {
         assert_almost_eq(semi_minor, 6_356_752.31);
     }
 
+    #[test]
+    fn geographic_and_projected_ellipsoid() {
+        let geographic = SpatialRef::from_epsg(4326).unwrap();
+        let projected = SpatialRef::from_epsg(32633).unwrap();
+
+        assert!(geographic.is_geographic());
+        assert!(!geographic.is_projected());
+        assert!(projected.is_projected());
+        assert!(!projected.is_geographic());
+        for srs in [&geographic, &projected] {
+            assert!(!srs.is_compound());
+            assert!(!srs.is_vertical());
+            assert!(!srs.is_geocentric());
+            assert!(!srs.is_local());
+            assert_almost_eq(srs.semi_major().unwrap(), 6_378_137.0);
+            assert_almost_eq(srs.inv_flattening().unwrap(), 298.257_223_563);
+        }
+    }
+
     #[test]
     fn proj_params() {
         let spatial_ref = SpatialRef::from_proj4(
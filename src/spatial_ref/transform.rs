@@ -24,6 +24,12 @@ impl Drop for CoordTransform {
 impl CoordTransform {
     /// Constructs a new transformation from `source` to `target`.
     ///
+    /// The order in which coordinates are passed to and returned from the transformation
+    /// follows the [`AxisMappingStrategy`][crate::spatial_ref::AxisMappingStrategy] of `source`
+    /// and `target` respectively. This defaults to the authority-defined axis order, so e.g.
+    /// `EPSG:4326` expects latitude first. The strategy is captured here; changing it on the
+    /// [`SpatialRef`]s afterwards does not affect an existing transformation.
+    ///
     /// See: [OCTNewCoordinateTransformation](https://gdal.org/api/ogr_srs_api.html#_CPPv430OCTNewCoordinateTransformation20OGRSpatialReferenceH20OGRSpatialReferenceH)
     pub fn new(source: &SpatialRef, target: &SpatialRef) -> errors::Result<CoordTransform> {
         let c_obj = unsafe {
@@ -42,7 +48,12 @@ impl CoordTransform {
     /// Constructs a new transformation from `source` to `target` with additional extended options
     /// defined by `options`: [`CoordTransformOptions`].
     ///
-    /// See: [OCTNewCoordinateTransformation](https://gdal.org/api/ogr_srs_api.html#_CPPv432OCTNewCoordinateTransformationEx20OGRSpatialReferenceH20OGRSpatialReferenceH35OGRCoordinateTransformationOptionsH)
+    /// When several coordinate operations exist between two datums, PROJ picks one based on
+    /// the area of use of each candidate. Constraining the area of interest, the accuracy or
+    /// the operation itself through `options` controls which pipeline is used, which matters
+    /// for the accuracy of datum shifts.
+    ///
+    /// See: [OCTNewCoordinateTransformationEx](https://gdal.org/api/ogr_srs_api.html#_CPPv432OCTNewCoordinateTransformationEx20OGRSpatialReferenceH20OGRSpatialReferenceH35OGRCoordinateTransformationOptionsH)
     pub fn new_with_options(
         source: &SpatialRef,
         target: &SpatialRef,
@@ -56,7 +67,7 @@ impl CoordTransform {
             )
         };
         if c_obj.is_null() {
-            return Err(_last_null_pointer_err("OCTNewCoordinateTransformation"));
+            return Err(_last_null_pointer_err("OCTNewCoordinateTransformationEx"));
         }
         Ok(Self {
             inner: c_obj,
@@ -68,6 +79,10 @@ impl CoordTransform {
     /// Transform bounding box, densifying the edges to account for nonlinear
     /// transformations.
     ///
+    /// This is the correct way to compute the extent of a reprojected area: transforming only
+    /// the corners misses the bulges of edges that become curves in the target CRS, as well as
+    /// poles and the anti-meridian inside the box.
+    ///
     /// # Arguments
     /// * `bounds` - array of [axis0_min, axis1_min, axis0_max, axis1_max],
     ///   interpreted in the axis order of the source SpatialRef,
@@ -124,11 +139,20 @@ impl CoordTransform {
 
     /// Transform coordinates in place.
     ///
+    /// All the points are handed to GDAL in a single call, which is much faster than
+    /// transforming them one at a time.
+    ///
     /// # Arguments
     /// * `x` - slice of x coordinates
     /// * `y` - slice of y coordinates (must match x in length)
     /// * `z` - slice of z coordinates, or an empty slice to ignore
     ///
+    /// Fails if any of the points can't be transformed; use
+    /// [`transform_coords_checked`][Self::transform_coords_checked] to find out which ones.
+    ///
+    /// # Panics
+    /// Panics if `y`, or a non-empty `z`, doesn't have the same length as `x`.
+    ///
     /// See: [OCTTransform](https://gdal.org/api/ogr_srs_api.html#_CPPv412OCTTransform28OGRCoordinateTransformationHiPdPdPd)
     pub fn transform_coords(
         &self,
@@ -186,6 +210,63 @@ impl CoordTransform {
         }
     }
 
+    /// Transform coordinates in place, reporting success for each point.
+    ///
+    /// Points that can't be transformed, e.g. because they lie outside the domain of the
+    /// target projection, are flagged with `false` in the returned vector instead of failing
+    /// the whole call. Their coordinates are set to `f64::INFINITY` by GDAL.
+    ///
+    /// # Arguments
+    /// * `x` - slice of x coordinates
+    /// * `y` - slice of y coordinates (must match x in length)
+    /// * `z` - slice of z coordinates, or an empty slice to ignore
+    ///
+    /// # Panics
+    /// Panics if `y`, or a non-empty `z`, doesn't have the same length as `x`.
+    ///
+    /// See: [OCTTransformEx](https://gdal.org/api/ogr_srs_api.html#_CPPv414OCTTransformEx28OGRCoordinateTransformationHiPdPdPdPi)
+    pub fn transform_coords_checked(
+        &self,
+        x: &mut [f64],
+        y: &mut [f64],
+        z: &mut [f64],
+    ) -> Vec<bool> {
+        let nb_coords = x.len();
+        assert_eq!(
+            nb_coords,
+            y.len(),
+            "transform coordinate slices have different lengths: {} != {}",
+            nb_coords,
+            y.len()
+        );
+        let z_ptr = if z.is_empty() {
+            null_mut()
+        } else {
+            assert_eq!(
+                nb_coords,
+                z.len(),
+                "transform coordinate slices have different lengths: {} != {}",
+                nb_coords,
+                z.len()
+            );
+            z.as_mut_ptr()
+        };
+
+        let mut success: Vec<c_int> = vec![0; nb_coords];
+        // The return value only summarizes `success`, so it is not checked here.
+        unsafe {
+            gdal_sys::OCTTransformEx(
+                self.inner,
+                nb_coords as c_int,
+                x.as_mut_ptr(),
+                y.as_mut_ptr(),
+                z_ptr,
+                success.as_mut_ptr(),
+            )
+        };
+        success.into_iter().map(|s| s != 0).collect()
+    }
+
     #[deprecated(since = "0.3.1", note = "use `transform_coords` instead")]
     pub fn transform_coord(&self, x: &mut [f64], y: &mut [f64], z: &mut [f64]) {
         self.transform_coords(x, y, z)
@@ -268,6 +349,84 @@ mod tests {
         assert_almost_eq(out_bounds[3], expected_bounds[3]);
     }
 
+    #[test]
+    fn transform_bounds_densified() {
+        let mut wgs84 = SpatialRef::from_epsg(4326).unwrap();
+        wgs84.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        // ETRS89-extended / LAEA Europe, northing first
+        let laea = SpatialRef::from_epsg(3035).unwrap();
+        let transform = CoordTransform::new(&wgs84, &laea).unwrap();
+
+        let bounds = [-10.0, 35.0, 30.0, 70.0];
+        let corners = transform.transform_bounds(&bounds, 0).unwrap();
+        let densified = transform.transform_bounds(&bounds, 21).unwrap();
+
+        // The southern edge bulges southwards in the middle, below the corners.
+        assert!(densified[0] < corners[0] - 10_000.0);
+        assert!(densified[0] <= corners[0] && densified[2] >= corners[2]);
+        assert!(densified[1] <= corners[1] && densified[3] >= corners[3]);
+    }
+
+    #[test]
+    fn transform_bounds_polar() {
+        let mut wgs84 = SpatialRef::from_epsg(4326).unwrap();
+        wgs84.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        // NSIDC Sea Ice Polar Stereographic North
+        let mut polar = SpatialRef::from_epsg(3413).unwrap();
+        polar.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        let transform = CoordTransform::new(&wgs84, &polar).unwrap();
+
+        let bounds = [-180.0, 60.0, 180.0, 90.0];
+        let out_bounds = transform.transform_bounds(&bounds, 21).unwrap();
+
+        // Transforming only the corners collapses the box to a segment from the pole,
+        // while the densified edges cover the whole circle of latitude 60°.
+        let mut xs = [bounds[0], bounds[2], bounds[0], bounds[2]];
+        let mut ys = [bounds[1], bounds[1], bounds[3], bounds[3]];
+        transform
+            .transform_coords(&mut xs, &mut ys, &mut [])
+            .unwrap();
+        let corners_min_x = xs.iter().copied().fold(f64::INFINITY, f64::min);
+        let corners_max_x = xs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        assert!(out_bounds[0] < 0.0 && out_bounds[2] > 0.0, "{out_bounds:?}");
+        assert!(out_bounds[1] < 0.0 && out_bounds[3] > 0.0, "{out_bounds:?}");
+        assert!(out_bounds[0] <= corners_min_x && out_bounds[2] >= corners_max_x);
+        assert!(out_bounds[2] - out_bounds[0] > 1.5 * (corners_max_x - corners_min_x));
+    }
+
+    #[test]
+    fn axis_order() {
+        let mut wgs84 = SpatialRef::from_epsg(4326).unwrap();
+        let webmercator = SpatialRef::from_epsg(3857).unwrap();
+
+        // EPSG:4326 is latitude/longitude ordered by default.
+        let authority = CoordTransform::new(&wgs84, &webmercator).unwrap();
+        let (mut xs, mut ys) = ([20.0], [10.0]);
+        authority
+            .transform_coords(&mut xs, &mut ys, &mut [])
+            .unwrap();
+        assert_almost_eq(xs[0], 1113194.9079327357);
+        assert_almost_eq(ys[0], 2273030.926987689);
+
+        // With the traditional GIS order, the same point is given as longitude/latitude.
+        wgs84.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        let traditional = CoordTransform::new(&wgs84, &webmercator).unwrap();
+        let (mut xs, mut ys) = ([10.0], [20.0]);
+        traditional
+            .transform_coords(&mut xs, &mut ys, &mut [])
+            .unwrap();
+        assert_almost_eq(xs[0], 1113194.9079327357);
+        assert_almost_eq(ys[0], 2273030.926987689);
+
+        // The transformation created earlier keeps the strategy it was built with.
+        let (mut xs, mut ys) = ([20.0], [10.0]);
+        authority
+            .transform_coords(&mut xs, &mut ys, &mut [])
+            .unwrap();
+        assert_almost_eq(xs[0], 1113194.9079327357);
+    }
+
     #[test]
     fn transform_coordinates() {
         let mut spatial_ref1 = SpatialRef::from_wkt("GEOGCS[\"WGS 84\",DATUM[\"WGS_1984\",SPHEROID[\"WGS 84\",6378137,298.257223563,AUTHORITY[\"EPSG\",7030]],TOWGS84[0,0,0,0,0,0,0],AUTHORITY[\"EPSG\",6326]],PRIMEM[\"Greenwich\",0,AUTHORITY[\"EPSG\",8901]],UNIT[\"DMSH\",0.0174532925199433,AUTHORITY[\"EPSG\",9108]],AXIS[\"Lat\",NORTH],AXIS[\"Long\",EAST],AUTHORITY[\"EPSG\",4326]]").unwrap();
@@ -289,6 +448,61 @@ mod tests {
         assert_almost_eq(zs[0], 32.0);
     }
 
+    #[test]
+    fn transform_coordinates_checked() {
+        let mut wgs84 = SpatialRef::from_epsg(4326).unwrap();
+        let mut webmercator = SpatialRef::from_epsg(3857).unwrap();
+        wgs84.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        webmercator.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+
+        let transform = CoordTransform::new(&wgs84, &webmercator).unwrap();
+        let mut xs = [10.0, 1000000.0, 0.0];
+        let mut ys = [20.0, 1000000.0, 0.0];
+        let success = transform.transform_coords_checked(&mut xs, &mut ys, &mut []);
+        assert_eq!(success, vec![true, false, true]);
+        assert_almost_eq(xs[0], 1113194.9079327357);
+        assert_almost_eq(ys[0], 2273030.926987689);
+        assert_almost_eq(xs[2], 0.0);
+        assert_almost_eq(ys[2], 0.0);
+    }
+
+    #[test]
+    fn transform_coordinates_batch_matches_single() {
+        let mut wgs84 = SpatialRef::from_epsg(4326).unwrap();
+        let mut utm = SpatialRef::from_epsg(32633).unwrap();
+        wgs84.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        utm.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        let transform = CoordTransform::new(&wgs84, &utm).unwrap();
+
+        let n = 10_000;
+        let mut xs: Vec<f64> = (0..n).map(|i| 12.0 + (i % 100) as f64 * 0.05).collect();
+        let mut ys: Vec<f64> = (0..n).map(|i| 40.0 + (i / 100) as f64 * 0.1).collect();
+        let expected: Vec<(f64, f64)> = xs
+            .iter()
+            .zip(&ys)
+            .map(|(&x, &y)| {
+                let (mut x, mut y) = ([x], [y]);
+                transform.transform_coords(&mut x, &mut y, &mut []).unwrap();
+                (x[0], y[0])
+            })
+            .collect();
+
+        let success = transform.transform_coords_checked(&mut xs, &mut ys, &mut []);
+        assert!(success.iter().all(|&s| s));
+        for ((x, y), (ex, ey)) in xs.iter().zip(&ys).zip(expected) {
+            assert_almost_eq(*x, ex);
+            assert_almost_eq(*y, ey);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "transform coordinate slices have different lengths")]
+    fn transform_coordinates_checked_length_mismatch() {
+        let wgs84 = SpatialRef::from_epsg(4326).unwrap();
+        let transform = CoordTransform::new(&wgs84, &wgs84).unwrap();
+        transform.transform_coords_checked(&mut [0.0, 1.0], &mut [0.0], &mut []);
+    }
+
     #[test]
     fn transform_ogr_geometry() {
         //let expected_value = "POLYGON ((5509543.150809700600803 1716062.191619219258428,5467122.000330002978444 1980151.204280239529908,5623571.028492723591626 2010213.310253676958382,5671834.921544363722205 1746968.078280254499987,5509543.150809700600803 1716062.191619219258428))";
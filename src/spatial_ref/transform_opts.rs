@@ -156,7 +156,8 @@ impl CoordTransformOptions {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::spatial_ref::SpatialRef;
+    use crate::spatial_ref::{AxisMappingStrategy, SpatialRef};
+    use crate::test_utils::SuppressGDALErrorLog;
 
     #[test]
     fn invalid_transformation() {
@@ -174,6 +175,66 @@ mod tests {
         assert!(matches!(err, GdalError::NullPointer { .. }), "{err:?}");
     }
 
+    #[test]
+    fn set_area_of_interest() {
+        let mut nad27 = SpatialRef::from_epsg(4267).unwrap();
+        let mut wgs84 = SpatialRef::from_epsg(4326).unwrap();
+        nad27.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        wgs84.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+
+        // Texas
+        let mut options = CoordTransformOptions::new().unwrap();
+        options
+            .set_area_of_interest(-106.65, 25.83, -93.51, 36.5)
+            .unwrap();
+        let trafo = CoordTransform::new_with_options(&nad27, &wgs84, &options).unwrap();
+
+        let mut xs = [-97.74];
+        let mut ys = [30.27];
+        trafo.transform_coords(&mut xs, &mut ys, &mut []).unwrap();
+        // The NAD27 to WGS 84 shift is in the order of tens of meters.
+        assert!((xs[0] - -97.74).abs() < 0.01);
+        assert!((ys[0] - 30.27).abs() < 0.01);
+    }
+
+    #[test]
+    fn area_of_interest_selects_pipeline() {
+        let mut nad27 = SpatialRef::from_epsg(4267).unwrap();
+        let mut wgs84 = SpatialRef::from_epsg(4326).unwrap();
+        nad27.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        wgs84.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+
+        let transform_with_aoi = |aoi: [f64; 4]| {
+            let mut options = CoordTransformOptions::new().unwrap();
+            options
+                .set_area_of_interest(aoi[0], aoi[1], aoi[2], aoi[3])
+                .unwrap();
+            let trafo = CoordTransform::new_with_options(&nad27, &wgs84, &options).unwrap();
+            let mut xs = [-97.74];
+            let mut ys = [30.27];
+            trafo.transform_coords(&mut xs, &mut ys, &mut []).unwrap();
+            (xs[0], ys[0])
+        };
+
+        // The same point is shifted differently depending on whether the operation
+        // for the conterminous US or for Alaska is chosen.
+        let texas = transform_with_aoi([-106.65, 25.83, -93.51, 36.5]);
+        let alaska = transform_with_aoi([-168.0, 54.0, -141.0, 71.0]);
+        assert!(
+            (texas.0 - alaska.0).abs() > 1e-7 || (texas.1 - alaska.1).abs() > 1e-7,
+            "{texas:?} {alaska:?}"
+        );
+    }
+
+    #[test]
+    fn set_invalid_area_of_interest() {
+        let _nolog = SuppressGDALErrorLog::new();
+        let mut options = CoordTransformOptions::new().unwrap();
+        assert!(options
+            .set_area_of_interest(-10.0, -95.0, 10.0, 10.0)
+            .is_err());
+    }
+
     #[test]
     fn set_coordinate_operation() {
         // Test case taken from:
@@ -0,0 +1,123 @@
+//! Typed access to the `SUBDATASETS` metadata domain
+
+use std::collections::BTreeMap;
+
+use crate::errors::Result;
+use crate::{Dataset, Metadata};
+
+/// A subdataset advertised by a container [`Dataset`], such as a variable in a
+/// NetCDF file or a page of a multi-page TIFF.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Subdataset {
+    /// Connection string that opens the subdataset, e.g. `NETCDF:"file.nc":var`.
+    pub name: String,
+    /// Human-readable description, or an empty string if the driver didn't provide one.
+    pub description: String,
+}
+
+impl Subdataset {
+    /// Open this subdataset with default options.
+    pub fn open(&self) -> Result<Dataset> {
+        Dataset::open(&self.name)
+    }
+}
+
+impl Dataset {
+    /// Fetch the subdatasets of this dataset, in the order reported by the driver.
+    ///
+    /// This parses the `SUBDATASET_<n>_NAME` and `SUBDATASET_<n>_DESC` entries of the
+    /// `SUBDATASETS` metadata domain. Returns an empty vector if the dataset has none.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::Dataset;
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let dataset = Dataset::open("fixtures/alldatatypes.nc")?;
+    /// for subdataset in dataset.subdatasets() {
+    ///     println!("{}: {}", subdataset.name, subdataset.description);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subdatasets(&self) -> Vec<Subdataset> {
+        let Some(entries) = self.metadata_domain("SUBDATASETS") else {
+            return Vec::new();
+        };
+
+        let mut subdatasets: BTreeMap<usize, (Option<String>, String)> = BTreeMap::new();
+        for entry in entries {
+            let Some((key, value)) = entry.split_once('=') else {
+                continue;
+            };
+            let Some((index, field)) = key
+                .strip_prefix("SUBDATASET_")
+                .and_then(|rest| rest.split_once('_'))
+            else {
+                continue;
+            };
+            let Ok(index) = index.parse() else {
+                continue;
+            };
+            let subdataset = subdatasets.entry(index).or_default();
+            match field {
+                "NAME" => subdataset.0 = Some(value.to_string()),
+                "DESC" => subdataset.1 = value.to_string(),
+                _ => {}
+            }
+        }
+
+        subdatasets
+            .into_values()
+            .filter_map(|(name, description)| {
+                Some(Subdataset {
+                    name: name?,
+                    description,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::raster::RasterCreationOptions;
+    use crate::test_utils::{fixture, InMemoryFixture};
+    use crate::{Dataset, DriverManager};
+
+    #[test]
+    fn test_subdatasets() {
+        let output = InMemoryFixture::new("subdatasets.tif");
+        let mem = DriverManager::get_driver_by_name("MEM").unwrap();
+        let gtiff = DriverManager::get_driver_by_name("GTiff").unwrap();
+        for (i, size) in [4, 2].into_iter().enumerate() {
+            let options = if i == 0 {
+                RasterCreationOptions::new()
+            } else {
+                RasterCreationOptions::from_iter(["APPEND_SUBDATASET=YES"])
+            };
+            mem.create("", size, size, 1)
+                .unwrap()
+                .create_copy(&gtiff, output.path(), &options)
+                .unwrap();
+        }
+
+        let dataset = Dataset::open(output.path()).unwrap();
+        let subdatasets = dataset.subdatasets();
+        assert_eq!(subdatasets.len(), 2);
+
+        let path = output.path().to_str().unwrap();
+        assert_eq!(subdatasets[0].name, format!("GTIFF_DIR:1:{path}"));
+        assert!(subdatasets[0].description.starts_with("Page 1"));
+        assert_eq!(subdatasets[1].name, format!("GTIFF_DIR:2:{path}"));
+
+        let first = subdatasets[0].open().unwrap();
+        assert_eq!(first.raster_size(), (4, 4));
+    }
+
+    #[test]
+    fn test_no_subdatasets() {
+        let dataset = Dataset::open(fixture("tinymarble.tif")).unwrap();
+        assert!(dataset.subdatasets().is_empty());
+    }
+}
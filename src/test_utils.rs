@@ -1,6 +1,10 @@
+use crate::errors::Result;
+use crate::raster::Buffer;
+use crate::spatial_ref::SpatialRef;
+use crate::vector::{Layer, LayerAccess, LayerOptions};
 use crate::vsi::unlink_mem_file;
-use crate::{Dataset, DatasetOptions};
-use gdal_sys::GDALAccess;
+use crate::{Dataset, DatasetOptions, DriverManager};
+use gdal_sys::{GDALAccess, OGRFieldType, OGRwkbGeometryType};
 use std::ffi::c_void;
 use std::marker::PhantomData;
 use std::path::{Path, PathBuf};
@@ -107,6 +111,55 @@ impl Drop for SuppressGDALErrorLog {
     }
 }
 
+/// Creates a 10x10 single band EPSG:4326 raster with its upper-left corner at `(x, 10)`.
+///
+/// An empty `path` keeps the tile in memory, anything else creates a GeoTIFF there.
+pub fn geo_tile(path: &str, x: f64) -> Dataset {
+    let driver_name = if path.is_empty() { "MEM" } else { "GTiff" };
+    let driver = DriverManager::get_driver_by_name(driver_name).unwrap();
+    let mut ds = driver.create(path, 10, 10, 1).unwrap();
+    ds.set_geo_transform(&[x, 1.0, 0.0, 10.0, 0.0, -1.0])
+        .unwrap();
+    ds.set_spatial_ref(&SpatialRef::from_epsg(4326).unwrap())
+        .unwrap();
+    ds.flush_cache().unwrap();
+    ds
+}
+
+/// Creates a 10x10 in-memory DEM whose pixel centres rise from 0.5 to 9.5 west to east.
+pub fn ramp_dem() -> Result<Dataset> {
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let mut ds = driver.create_with_band_type::<f32, _>("", 10, 10, 1)?;
+    ds.set_geo_transform(&[0.0, 1.0, 0.0, 10.0, 0.0, -1.0])?;
+    let data = (0..10)
+        .flat_map(|_| (0..10).map(|x| x as f32 + 0.5))
+        .collect();
+    let mut buffer = Buffer::new((10, 10), data);
+    ds.rasterband(1)?.write((0, 0), (10, 10), &mut buffer)?;
+    Ok(ds)
+}
+
+/// Creates an empty in-memory vector dataset, to hold the layers of [`memory_layer`].
+pub fn memory_dataset() -> Result<Dataset> {
+    DriverManager::get_driver_by_name("Memory")?.create_vector_only("")
+}
+
+/// Creates the layer `name` in `ds`, with geometry type `ty` and the `(name, type)` `fields`.
+pub fn memory_layer<'a>(
+    ds: &'a mut Dataset,
+    name: &str,
+    ty: OGRwkbGeometryType::Type,
+    fields: &[(&str, OGRFieldType::Type)],
+) -> Result<Layer<'a>> {
+    let layer = ds.create_layer(LayerOptions {
+        name,
+        ty,
+        ..Default::default()
+    })?;
+    layer.create_defn_fields(fields)?;
+    Ok(layer)
+}
+
 /// Copies the given file to a temporary file and opens it for writing. When the returned
 /// `TempPath` is dropped, the file is deleted.
 pub fn open_gpkg_for_update(path: &Path) -> (TempPath, Dataset) {
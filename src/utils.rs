@@ -1,7 +1,7 @@
 use std::ffi::{c_char, CStr, CString};
 use std::path::{Path, PathBuf};
 
-use gdal_sys::CPLErr;
+use gdal_sys::{CPLErr, OGRErr};
 
 use crate::errors::*;
 
@@ -82,6 +82,31 @@ pub fn _last_null_pointer_err(method_name: &'static str) -> GdalError {
     }
 }
 
+/// Returns the last CPL error if GDAL reported a failure, e.g. why a file couldn't be
+/// opened, or a [`GdalError::NullPointer`] error for `method_name` otherwise.
+pub fn _last_err_or_null_pointer(method_name: &'static str) -> GdalError {
+    let last_err_class = unsafe { gdal_sys::CPLGetLastErrorType() };
+    if last_err_class == CPLErr::CE_Failure || last_err_class == CPLErr::CE_Fatal {
+        _last_cpl_err(last_err_class)
+    } else {
+        _last_null_pointer_err(method_name)
+    }
+}
+
+/// Like [`_last_err_or_null_pointer`], for calls that signal failure with a sentinel
+/// value; falls back to an [`OGRErr::OGRERR_FAILURE`] error for `method_name`.
+pub fn _last_err_or_ogr_failure(method_name: &'static str) -> GdalError {
+    let last_err_class = unsafe { gdal_sys::CPLGetLastErrorType() };
+    if last_err_class == CPLErr::CE_Failure || last_err_class == CPLErr::CE_Fatal {
+        _last_cpl_err(last_err_class)
+    } else {
+        GdalError::OgrError {
+            err: OGRErr::OGRERR_FAILURE,
+            method_name,
+        }
+    }
+}
+
 pub fn _path_to_c_string(path: &Path) -> Result<CString> {
     let path_str = path.to_string_lossy();
     CString::new(path_str.as_ref()).map_err(Into::into)
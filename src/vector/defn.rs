@@ -1,7 +1,8 @@
 use std::ffi::{c_int, CString};
 
 use gdal_sys::{
-    OGRFeatureDefnH, OGRFieldDefnH, OGRFieldType, OGRGeomFieldDefnH, OGRwkbGeometryType,
+    OGRFeatureDefnH, OGRFieldDefnH, OGRFieldSubType, OGRFieldType, OGRGeomFieldDefnH,
+    OGRwkbGeometryType,
 };
 
 use crate::spatial_ref::SpatialRef;
@@ -118,6 +119,55 @@ impl Defn {
     }
 }
 
+/// Subtypes that refine the [`OGRFieldType`] of a field.
+///
+/// See: [`OGRFieldSubType`](https://gdal.org/api/vector_c_api.html#_CPPv415OGRFieldSubType)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FieldSubType {
+    /// No subtype. This is the default.
+    None,
+    /// Boolean stored in an [`OGRFieldType::OFTInteger`] or
+    /// [`OGRFieldType::OFTIntegerList`] field.
+    Boolean,
+    /// Signed 16-bit integer stored in an [`OGRFieldType::OFTInteger`] or
+    /// [`OGRFieldType::OFTIntegerList`] field.
+    Int16,
+    /// Single precision float stored in an [`OGRFieldType::OFTReal`] or
+    /// [`OGRFieldType::OFTRealList`] field.
+    Float32,
+    /// JSON content stored in an [`OGRFieldType::OFTString`] field.
+    Json,
+    /// UUID string stored in an [`OGRFieldType::OFTString`] field.
+    Uuid,
+}
+
+impl FieldSubType {
+    /// Creates a Rust [`FieldSubType`] from a C API [`OGRFieldSubType`] value.
+    pub(crate) fn from_c_int(subtype: OGRFieldSubType::Type) -> Self {
+        match subtype {
+            OGRFieldSubType::OFSTNone => Self::None,
+            OGRFieldSubType::OFSTBoolean => Self::Boolean,
+            OGRFieldSubType::OFSTInt16 => Self::Int16,
+            OGRFieldSubType::OFSTFloat32 => Self::Float32,
+            OGRFieldSubType::OFSTJSON => Self::Json,
+            OGRFieldSubType::OFSTUUID => Self::Uuid,
+            _ => unreachable!("GDAL has implemented a new type of `OGRFieldSubType`"),
+        }
+    }
+
+    /// Returns the C API int value of this field subtype.
+    pub fn c_int(&self) -> OGRFieldSubType::Type {
+        match self {
+            Self::None => OGRFieldSubType::OFSTNone,
+            Self::Boolean => OGRFieldSubType::OFSTBoolean,
+            Self::Int16 => OGRFieldSubType::OFSTInt16,
+            Self::Float32 => OGRFieldSubType::OFSTFloat32,
+            Self::Json => OGRFieldSubType::OFSTJSON,
+            Self::Uuid => OGRFieldSubType::OFSTUUID,
+        }
+    }
+}
+
 pub struct FieldIterator<'a> {
     defn: &'a Defn,
     c_feature_defn: OGRFeatureDefnH,
@@ -167,6 +217,11 @@ impl<'a> Field<'a> {
         unsafe { gdal_sys::OGR_Fld_GetType(self.c_field_defn) }
     }
 
+    /// Get the subtype of this field, which refines its [`Field::field_type`].
+    pub fn subtype(&'a self) -> FieldSubType {
+        FieldSubType::from_c_int(unsafe { gdal_sys::OGR_Fld_GetSubType(self.c_field_defn) })
+    }
+
     /// Get the formatting width for this field.
     ///
     /// Zero means no specified width.
@@ -104,12 +104,11 @@ impl<'a> Feature<'a> {
                 Ok(Some(FieldValue::RealValue(rv)))
             }
             OGRFieldType::OFTRealList => {
-                let rv = unsafe {
-                    let mut len: i32 = 0;
-                    let ptr =
-                        gdal_sys::OGR_F_GetFieldAsDoubleList(self.c_feature, field_idx, &mut len);
-                    slice::from_raw_parts(ptr, len as usize).to_vec()
+                let mut len: c_int = 0;
+                let ptr = unsafe {
+                    gdal_sys::OGR_F_GetFieldAsDoubleList(self.c_feature, field_idx, &mut len)
                 };
+                let rv = unsafe { _list(ptr, len) };
                 Ok(Some(FieldValue::RealListValue(rv)))
             }
             OGRFieldType::OFTInteger => {
@@ -117,12 +116,11 @@ impl<'a> Feature<'a> {
                 Ok(Some(FieldValue::IntegerValue(rv)))
             }
             OGRFieldType::OFTIntegerList => {
-                let rv = unsafe {
-                    let mut len: i32 = 0;
-                    let ptr =
-                        gdal_sys::OGR_F_GetFieldAsIntegerList(self.c_feature, field_idx, &mut len);
-                    slice::from_raw_parts(ptr, len as usize).to_vec()
+                let mut len: c_int = 0;
+                let ptr = unsafe {
+                    gdal_sys::OGR_F_GetFieldAsIntegerList(self.c_feature, field_idx, &mut len)
                 };
+                let rv = unsafe { _list(ptr, len) };
                 Ok(Some(FieldValue::IntegerListValue(rv)))
             }
             OGRFieldType::OFTInteger64 => {
@@ -130,15 +128,11 @@ impl<'a> Feature<'a> {
                 Ok(Some(FieldValue::Integer64Value(rv)))
             }
             OGRFieldType::OFTInteger64List => {
-                let rv = unsafe {
-                    let mut len: i32 = 0;
-                    let ptr = gdal_sys::OGR_F_GetFieldAsInteger64List(
-                        self.c_feature,
-                        field_idx,
-                        &mut len,
-                    );
-                    slice::from_raw_parts(ptr, len as usize).to_vec()
+                let mut len: c_int = 0;
+                let ptr = unsafe {
+                    gdal_sys::OGR_F_GetFieldAsInteger64List(self.c_feature, field_idx, &mut len)
                 };
+                let rv = unsafe { _list(ptr, len) };
                 Ok(Some(FieldValue::Integer64ListValue(rv)))
             }
             OGRFieldType::OFTDateTime => Ok(Some(FieldValue::DateTimeValue(
@@ -301,6 +295,142 @@ impl<'a> Feature<'a> {
         Ok(value)
     }
 
+    /// Get the value of the specified field as a list of [`i32`].
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
+    ///
+    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(Some(vec![]))` if the list is empty or the field can't be converted.
+    ///
+    pub fn field_as_integer_list(&self, field_idx: usize) -> Result<Option<Vec<i32>>> {
+        if field_idx >= self.field_count() {
+            return Err(GdalError::InvalidFieldIndex {
+                index: field_idx,
+                method_name: "field_as_integer_list",
+            });
+        }
+
+        let idx = field_idx.try_into()?;
+        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, idx) } != 0 {
+            return Ok(None);
+        }
+
+        let mut len: c_int = 0;
+        let ptr = unsafe { gdal_sys::OGR_F_GetFieldAsIntegerList(self.c_feature, idx, &mut len) };
+        let value = unsafe { _list(ptr, len) };
+
+        Ok(Some(value))
+    }
+
+    /// Get the value of the specified field as a list of [`i64`].
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
+    ///
+    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(Some(vec![]))` if the list is empty or the field can't be converted.
+    ///
+    pub fn field_as_integer64_list(&self, field_idx: usize) -> Result<Option<Vec<i64>>> {
+        if field_idx >= self.field_count() {
+            return Err(GdalError::InvalidFieldIndex {
+                index: field_idx,
+                method_name: "field_as_integer64_list",
+            });
+        }
+
+        let idx = field_idx.try_into()?;
+        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, idx) } != 0 {
+            return Ok(None);
+        }
+
+        let mut len: c_int = 0;
+        let ptr = unsafe { gdal_sys::OGR_F_GetFieldAsInteger64List(self.c_feature, idx, &mut len) };
+        let value = unsafe { _list(ptr, len) };
+
+        Ok(Some(value))
+    }
+
+    /// Get the value of the specified field as a list of [`f64`].
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
+    ///
+    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(Some(vec![]))` if the list is empty or the field can't be converted.
+    ///
+    pub fn field_as_double_list(&self, field_idx: usize) -> Result<Option<Vec<f64>>> {
+        if field_idx >= self.field_count() {
+            return Err(GdalError::InvalidFieldIndex {
+                index: field_idx,
+                method_name: "field_as_double_list",
+            });
+        }
+
+        let idx = field_idx.try_into()?;
+        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, idx) } != 0 {
+            return Ok(None);
+        }
+
+        let mut len: c_int = 0;
+        let ptr = unsafe { gdal_sys::OGR_F_GetFieldAsDoubleList(self.c_feature, idx, &mut len) };
+        let value = unsafe { _list(ptr, len) };
+
+        Ok(Some(value))
+    }
+
+    /// Get the value of the specified field as a list of [`String`]s.
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
+    ///
+    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(Some(vec![]))` if the list is empty or the field can't be converted.
+    ///
+    pub fn field_as_string_list(&self, field_idx: usize) -> Result<Option<Vec<String>>> {
+        if field_idx >= self.field_count() {
+            return Err(GdalError::InvalidFieldIndex {
+                index: field_idx,
+                method_name: "field_as_string_list",
+            });
+        }
+
+        let idx = field_idx.try_into()?;
+        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, idx) } != 0 {
+            return Ok(None);
+        }
+
+        let value =
+            _string_array(unsafe { gdal_sys::OGR_F_GetFieldAsStringList(self.c_feature, idx) });
+
+        Ok(Some(value))
+    }
+
+    /// Get the value of the specified field as raw bytes.
+    ///
+    /// This is intended for [`OGRFieldType::OFTBinary`] fields, but GDAL can also
+    /// return the content of string fields this way.
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
+    ///
+    /// Returns `Ok(None)` if the field is null.
+    ///
+    pub fn field_as_binary(&self, field_idx: usize) -> Result<Option<Vec<u8>>> {
+        if field_idx >= self.field_count() {
+            return Err(GdalError::InvalidFieldIndex {
+                index: field_idx,
+                method_name: "field_as_binary",
+            });
+        }
+
+        let idx = field_idx.try_into()?;
+        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, idx) } != 0 {
+            return Ok(None);
+        }
+
+        let mut len: c_int = 0;
+        let ptr = unsafe { gdal_sys::OGR_F_GetFieldAsBinary(self.c_feature, idx, &mut len) };
+        let value = unsafe { _list(ptr, len) };
+
+        Ok(Some(value))
+    }
+
     /// Get the value of the specified field as a [`DateTime<FixedOffset>`].
     ///
     /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
@@ -331,11 +461,11 @@ impl<'a> Feature<'a> {
         let mut day: c_int = 0;
         let mut hour: c_int = 0;
         let mut minute: c_int = 0;
-        let mut second: c_int = 0;
+        let mut second: f32 = 0.0;
         let mut tzflag: c_int = 0;
 
         let success = unsafe {
-            gdal_sys::OGR_F_GetFieldAsDateTime(
+            gdal_sys::OGR_F_GetFieldAsDateTimeEx(
                 self.c_feature,
                 field_id,
                 &mut year,
@@ -350,10 +480,18 @@ impl<'a> Feature<'a> {
         if success == 0 {
             return Err(GdalError::OgrError {
                 err: OGRErr::OGRERR_FAILURE,
-                method_name: "OGR_F_GetFieldAsDateTime",
+                method_name: "OGR_F_GetFieldAsDateTimeEx",
             });
         }
 
+        // GDAL keeps fractional seconds with millisecond precision, and may report a leap
+        // second as 60. Seconds past 59 are carried into the next minute, like the rounded
+        // milliseconds.
+        let second = second as f64;
+        let whole_seconds = second.floor();
+        let carry = chrono::Duration::seconds((whole_seconds as i64 - 59).max(0))
+            + chrono::Duration::milliseconds(((second - whole_seconds) * 1000.0).round() as i64);
+
         // from https://github.com/OSGeo/gdal/blob/33a8a0edc764253b582e194d330eec3b83072863/gdal/ogr/ogrutils.cpp#L1309
         let tzoffset_secs = if tzflag == 0 || tzflag == 100 {
             0
@@ -368,8 +506,9 @@ impl<'a> Feature<'a> {
                 day as u32,
                 hour as u32,
                 minute as u32,
-                second as u32,
-            );
+                (whole_seconds as u32).min(59),
+            )
+            .map(|d| d + carry);
         match rv {
             LocalResult::None => Err(
                 GdalError::DateError(format!("Unable to reconstruct valid date from fields: {year}, {month}, {day}, {hour}, {minute}, {second}"))
@@ -505,6 +644,20 @@ impl<'a> Feature<'a> {
         Ok(())
     }
 
+    /// Set the value of an [`OGRFieldType::OFTBinary`] field to a copy of `value`.
+    pub fn set_field_binary(&mut self, field_idx: usize, value: &[u8]) -> Result<()> {
+        let idx = field_idx.try_into()?;
+        let len = c_int::try_from(value.len())?;
+        unsafe {
+            gdal_sys::OGR_F_SetFieldBinary(self.c_feature, idx, len, value.as_ptr() as *const _)
+        };
+        Ok(())
+    }
+
+    /// Set the value of the specified field from a [`DateTime<FixedOffset>`].
+    ///
+    /// The UTC offset is stored along with the value, rounded to a multiple of
+    /// 15 minutes, and fractional seconds are kept to millisecond precision.
     pub fn set_field_datetime(
         &mut self,
         field_idx: usize,
@@ -516,15 +669,12 @@ impl<'a> Feature<'a> {
         let day = value.day() as c_int;
         let hour = value.hour() as c_int;
         let minute = value.minute() as c_int;
-        let second = value.second() as c_int;
-        let tzflag: c_int = if value.offset().local_minus_utc() == 0 {
-            0
-        } else {
-            100 + (value.offset().local_minus_utc() / (15 * 60))
-        };
+        let second = value.second() as f32 + value.timestamp_subsec_millis() as f32 / 1000.0;
+        // 100 is UTC, and each step away from it is a 15 minute offset
+        let tzflag: c_int = 100 + (value.offset().local_minus_utc() / (15 * 60));
 
         unsafe {
-            gdal_sys::OGR_F_SetFieldDateTime(
+            gdal_sys::OGR_F_SetFieldDateTimeEx(
                 self.c_feature,
                 idx,
                 year,
@@ -591,6 +741,47 @@ impl<'a> Feature<'a> {
         Ok(())
     }
 
+    /// Returns `true` if the field has been explicitly marked as null, e.g. with
+    /// [`Feature::set_field_null`]. An unset field is not null.
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
+    ///
+    /// See: [`OGRFeature::IsFieldNull`][IsFieldNull]
+    ///
+    /// [IsFieldNull]: https://gdal.org/api/ogrfeature_cpp.html#_CPPv4NK10OGRFeature11IsFieldNullEi
+    pub fn is_field_null(&self, field_idx: usize) -> Result<bool> {
+        if field_idx >= self.field_count() {
+            return Err(GdalError::InvalidFieldIndex {
+                index: field_idx,
+                method_name: "is_field_null",
+            });
+        }
+        let idx = field_idx.try_into()?;
+        Ok(unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature(), idx) } != 0)
+    }
+
+    /// Returns `true` if the field has a value or has been marked as null, and
+    /// `false` if it is unset.
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
+    ///
+    /// See: [`OGRFeature::IsFieldSet`][IsFieldSet]
+    ///
+    /// [IsFieldSet]: https://gdal.org/api/ogrfeature_cpp.html#_CPPv4NK10OGRFeature10IsFieldSetEi
+    pub fn is_field_set(&self, field_idx: usize) -> Result<bool> {
+        if field_idx >= self.field_count() {
+            return Err(GdalError::InvalidFieldIndex {
+                index: field_idx,
+                method_name: "is_field_set",
+            });
+        }
+        let idx = field_idx.try_into()?;
+        Ok(unsafe { gdal_sys::OGR_F_IsFieldSet(self.c_feature(), idx) } != 0)
+    }
+
+    /// Set the geometry of the feature's first geometry field.
+    ///
+    /// See [`Feature::set_geometry_by_index`] for features with several geometry fields.
     pub fn set_geometry(&mut self, geom: Geometry) -> Result<()> {
         let rv = unsafe { gdal_sys::OGR_F_SetGeometry(self.c_feature, geom.c_geometry()) };
         if rv != OGRErr::OGRERR_NONE {
@@ -603,6 +794,32 @@ impl<'a> Feature<'a> {
         Ok(())
     }
 
+    /// Set the geometry of the geometry field at index `idx`.
+    ///
+    /// The geometry fields of a feature are listed by [`Defn::geom_fields`], in index order,
+    /// and [`Feature::geometry_field_index`] looks one up by name.
+    ///
+    /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
+    pub fn set_geometry_by_index(&mut self, idx: usize, geom: Geometry) -> Result<()> {
+        if idx >= self.geometry.len() {
+            return Err(GdalError::InvalidFieldIndex {
+                index: idx,
+                method_name: "set_geometry_by_index",
+            });
+        }
+        let rv = unsafe {
+            gdal_sys::OGR_F_SetGeomField(self.c_feature, idx as c_int, geom.c_geometry())
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_F_SetGeomField",
+            });
+        }
+        self.geometry[idx] = geom;
+        Ok(())
+    }
+
     pub fn field_count(&self) -> usize {
         let count = unsafe { gdal_sys::OGR_F_GetFieldCount(self.c_feature) };
         count as usize
@@ -791,6 +1008,17 @@ impl Drop for OwnedFeatureIterator {
     }
 }
 
+/// Copy a list returned by GDAL into a [`Vec`], treating a null pointer as empty.
+///
+/// # Safety
+/// If `ptr` is not null, it must point to at least `len` values.
+unsafe fn _list<T: Copy>(ptr: *const T, len: c_int) -> Vec<T> {
+    if ptr.is_null() || len <= 0 {
+        return Vec::new();
+    }
+    slice::from_raw_parts(ptr, len as usize).to_vec()
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum FieldValue {
     IntegerValue(i32),
@@ -881,8 +1109,9 @@ pub fn field_type_to_name(ty: OGRFieldType::Type) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::fixture;
+    use crate::test_utils::{fixture, memory_dataset, memory_layer};
     use crate::Dataset;
+    use gdal_sys::OGRwkbGeometryType;
 
     #[test]
     fn test_field_type_to_name() {
@@ -891,6 +1120,47 @@ mod tests {
         field_type_to_name(4372521);
     }
 
+    #[test]
+    fn test_secondary_geometry_field() -> Result<()> {
+        let mut ds = memory_dataset()?;
+        let mut layer = memory_layer(&mut ds, "sites", OGRwkbGeometryType::wkbPoint, &[])?;
+        unsafe {
+            let c_name = CString::new("footprint")?;
+            let c_geom_field =
+                gdal_sys::OGR_GFld_Create(c_name.as_ptr(), OGRwkbGeometryType::wkbPolygon);
+            let rv = gdal_sys::OGR_L_CreateGeomField(layer.c_layer(), c_geom_field, 1);
+            gdal_sys::OGR_GFld_Destroy(c_geom_field);
+            assert_eq!(rv, OGRErr::OGRERR_NONE);
+        }
+
+        let geom_fields: Vec<_> = layer.defn().geom_fields().map(|f| f.name()).collect();
+        assert_eq!(geom_fields.len(), 2);
+        assert_eq!(geom_fields[1], "footprint");
+
+        let footprint = layer.defn().geometry_field_index("footprint")?;
+        assert_eq!(footprint, 1);
+        {
+            let mut feature = Feature::new(layer.defn())?;
+            feature.set_geometry(Geometry::from_wkt("POINT (1 1)")?)?;
+            feature.set_geometry_by_index(
+                footprint,
+                Geometry::from_wkt("POLYGON ((0 0,0 2,2 2,2 0,0 0))")?,
+            )?;
+            assert!(feature
+                .set_geometry_by_index(2, Geometry::from_wkt("POINT (0 0)")?)
+                .is_err());
+            feature.create(&layer)?;
+        }
+
+        let feature = layer.features().next().unwrap();
+        assert_eq!(feature.geometry().unwrap().wkt()?, "POINT (1 1)");
+        assert_eq!(
+            feature.geometry_by_index(footprint)?.wkt()?,
+            "POLYGON ((0 0,0 2,2 2,2 0,0 0))"
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_field_set_null() {
         let ds = Dataset::open(fixture("roads.geojson")).unwrap();
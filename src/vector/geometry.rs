@@ -3,15 +3,16 @@ use std::{
     ffi::{c_double, c_int},
     fmt::{self, Debug, Formatter},
     marker::PhantomData,
-    mem::MaybeUninit,
+    mem::{self, MaybeUninit},
     ops::{Deref, DerefMut},
+    ptr,
 };
 
 use gdal_sys::{OGRErr, OGRGeometryH, OGRwkbGeometryType};
 
 use crate::errors::*;
 use crate::spatial_ref::SpatialRef;
-use crate::utils::{_last_null_pointer_err, _string};
+use crate::utils::{_last_err_or_ogr_failure, _last_null_pointer_err, _string};
 use crate::vector::{Envelope, Envelope3D};
 
 /// OGR Geometry
@@ -64,6 +65,10 @@ impl Geometry {
         }
     }
 
+    /// Create an empty geometry of type `wkb_type`, to be filled with
+    /// [`add_point`][Self::add_point] and friends, or [`add_geometry`][Self::add_geometry].
+    ///
+    /// See: [`OGR_G_CreateGeometry`](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_G_CreateGeometry18OGRwkbGeometryType)
     pub fn empty(wkb_type: OGRwkbGeometryType::Type) -> Result<Geometry> {
         let c_geom = unsafe { gdal_sys::OGR_G_CreateGeometry(wkb_type) };
         if c_geom.is_null() {
@@ -72,6 +77,9 @@ impl Geometry {
         Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
     }
 
+    /// Test if the geometry has no points.
+    ///
+    /// See: [`OGR_G_IsEmpty`](https://gdal.org/api/vector_c_api.html#_CPPv412OGR_G_IsEmpty12OGRGeometryH)
     pub fn is_empty(&self) -> bool {
         unsafe { gdal_sys::OGR_G_IsEmpty(self.c_geometry()) == 1 }
     }
@@ -141,6 +149,70 @@ impl Geometry {
         };
     }
 
+    /// Replace all points of a line string or linear ring with `points`, in a single call.
+    ///
+    /// This is much faster than setting the points one by one for long lines.
+    /// The geometry becomes 3D. A point geometry is set to the first entry of `points`,
+    /// and geometry types without points, like polygons, are left untouched.
+    ///
+    /// Returns [`GdalError::BadArgument`] if `points` is empty.
+    ///
+    /// See: [`OGR_G_SetPoints`](https://gdal.org/api/vector_c_api.html#_CPPv415OGR_G_SetPoints12OGRGeometryHiPKviPKviPKvi)
+    pub fn set_points(&mut self, points: &[(f64, f64, f64)]) -> Result<()> {
+        if points.is_empty() {
+            return Err(GdalError::BadArgument(
+                "`points` must not be empty".to_string(),
+            ));
+        }
+        let count = c_int::try_from(points.len())?;
+        let base = points.as_ptr();
+        let stride = mem::size_of::<(f64, f64, f64)>() as c_int;
+        unsafe {
+            gdal_sys::OGR_G_SetPoints(
+                self.c_geometry(),
+                count,
+                ptr::addr_of!((*base).0).cast(),
+                stride,
+                ptr::addr_of!((*base).1).cast(),
+                stride,
+                ptr::addr_of!((*base).2).cast(),
+                stride,
+            )
+        };
+        Ok(())
+    }
+
+    /// Replace all points of a line string or linear ring with the 2D `points`, in a single call.
+    ///
+    /// See [`set_points`][Self::set_points].
+    ///
+    /// Returns [`GdalError::BadArgument`] if `points` is empty.
+    ///
+    /// See: [`OGR_G_SetPoints`](https://gdal.org/api/vector_c_api.html#_CPPv415OGR_G_SetPoints12OGRGeometryHiPKviPKviPKvi)
+    pub fn set_points_2d(&mut self, points: &[(f64, f64)]) -> Result<()> {
+        if points.is_empty() {
+            return Err(GdalError::BadArgument(
+                "`points` must not be empty".to_string(),
+            ));
+        }
+        let count = c_int::try_from(points.len())?;
+        let base = points.as_ptr();
+        let stride = mem::size_of::<(f64, f64)>() as c_int;
+        unsafe {
+            gdal_sys::OGR_G_SetPoints(
+                self.c_geometry(),
+                count,
+                ptr::addr_of!((*base).0).cast(),
+                stride,
+                ptr::addr_of!((*base).1).cast(),
+                stride,
+                ptr::null(),
+                0,
+            )
+        };
+        Ok(())
+    }
+
     pub fn set_point_2d(&mut self, i: usize, p: (f64, f64)) {
         let (x, y) = p;
         unsafe {
@@ -148,6 +220,9 @@ impl Geometry {
         };
     }
 
+    /// Append a 3D point to a line string or linear ring, or set the coordinates of a point.
+    ///
+    /// See: [`OGR_G_AddPoint`](https://gdal.org/api/vector_c_api.html#_CPPv414OGR_G_AddPoint12OGRGeometryHddd)
     pub fn add_point(&mut self, p: (f64, f64, f64)) {
         let (x, y, z) = p;
         unsafe {
@@ -160,6 +235,9 @@ impl Geometry {
         };
     }
 
+    /// Like [`add_point`][Self::add_point], without a Z coordinate.
+    ///
+    /// See: [`OGR_G_AddPoint_2D`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_AddPoint_2D12OGRGeometryHdd)
     pub fn add_point_2d(&mut self, p: (f64, f64)) {
         let (x, y) = p;
         unsafe { gdal_sys::OGR_G_AddPoint_2D(self.c_geometry(), x as c_double, y as c_double) };
@@ -219,26 +297,108 @@ impl Geometry {
         (x, y, z, m)
     }
 
-    /// Appends all points in the geometry to `out_points`, as XYZ.
+    /// Appends all points in the geometry to `out_points`, as XYZ, and returns their number.
+    ///
+    /// The coordinates are copied in a single call, which is much faster than
+    /// [`get_point`][Self::get_point] for long lines. Z is `0` for 2D geometries.
     ///
     /// For some geometry types, like polygons, that don't consist of points, `out_points` will not be modified.
+    ///
+    /// See: [`OGR_G_GetPoints`](https://gdal.org/api/vector_c_api.html#_CPPv415OGR_G_GetPoints12OGRGeometryHPviPviPvi)
     pub fn get_points(&self, out_points: &mut Vec<(f64, f64, f64)>) -> usize {
-        // Consider replacing logic with
-        // [OGR_G_GetPoints](https://gdal.org/en/stable/api/vector_c_api.html#_CPPv415OGR_G_GetPoints12OGRGeometryHPviPviPvi)
-        let length = unsafe { gdal_sys::OGR_G_GetPointCount(self.c_geometry()) };
-        out_points.extend((0..length).map(|i| self.get_point(i)));
-        length as usize
+        let length = self.point_count();
+        if length == 0 {
+            return 0;
+        }
+        let start = out_points.len();
+        out_points.resize(start + length, (0.0, 0.0, 0.0));
+        let base = out_points[start..].as_mut_ptr();
+        let stride = mem::size_of::<(f64, f64, f64)>() as c_int;
+        let is_3d = unsafe { gdal_sys::OGR_G_GetCoordinateDimension(self.c_geometry()) } == 3;
+        unsafe {
+            gdal_sys::OGR_G_GetPoints(
+                self.c_geometry(),
+                ptr::addr_of_mut!((*base).0).cast(),
+                stride,
+                ptr::addr_of_mut!((*base).1).cast(),
+                stride,
+                if is_3d {
+                    ptr::addr_of_mut!((*base).2).cast()
+                } else {
+                    ptr::null_mut()
+                },
+                stride,
+            )
+        };
+        length
     }
 
-    /// Appends all points in the geometry to `out_points`, as XYZM.
+    /// Appends all points in the geometry to `out_points`, as XYZM, and returns their number.
+    ///
+    /// Like [`get_points`][Self::get_points], the coordinates are copied in a single call.
+    /// Z and M are `0` for geometries without them.
     ///
     /// For some geometry types, like polygons, that don't consist of points, `out_points` will not be modified.
+    ///
+    /// See: [`OGR_G_GetPointsZM`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_GetPointsZM12OGRGeometryHPviPviPviPvi)
     pub fn get_points_zm(&self, out_points: &mut Vec<(f64, f64, f64, f64)>) -> usize {
-        // Consider replacing logic with
-        // [OGR_G_GetPoints](https://gdal.org/en/stable/api/vector_c_api.html#_CPPv415OGR_G_GetPoints12OGRGeometryHPviPviPvi)
-        let length = unsafe { gdal_sys::OGR_G_GetPointCount(self.c_geometry()) };
-        out_points.extend((0..length).map(|i| self.get_point_zm(i)));
-        length as usize
+        let length = self.point_count();
+        if length == 0 {
+            return 0;
+        }
+        let start = out_points.len();
+        out_points.resize(start + length, (0.0, 0.0, 0.0, 0.0));
+        let base = out_points[start..].as_mut_ptr();
+        let stride = mem::size_of::<(f64, f64, f64, f64)>() as c_int;
+        let (is_3d, is_measured) = unsafe {
+            (
+                gdal_sys::OGR_G_Is3D(self.c_geometry()) != 0,
+                gdal_sys::OGR_G_IsMeasured(self.c_geometry()) != 0,
+            )
+        };
+        unsafe {
+            gdal_sys::OGR_G_GetPointsZM(
+                self.c_geometry(),
+                ptr::addr_of_mut!((*base).0).cast(),
+                stride,
+                ptr::addr_of_mut!((*base).1).cast(),
+                stride,
+                if is_3d {
+                    ptr::addr_of_mut!((*base).2).cast()
+                } else {
+                    ptr::null_mut()
+                },
+                stride,
+                if is_measured {
+                    ptr::addr_of_mut!((*base).3).cast()
+                } else {
+                    ptr::null_mut()
+                },
+                stride,
+            )
+        };
+        length
+    }
+
+    /// Returns an iterator over the points of a line string or a point geometry, as XYZ.
+    ///
+    /// Like [`get_points`][Self::get_points], this yields nothing for geometries that don't
+    /// directly consist of points, like polygons; iterate over their rings with
+    /// [`get_geometry`][Self::get_geometry] instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::vector::Geometry;
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let line = Geometry::from_wkt("LINESTRING (0 0, 1 1, 2 0)")?;
+    /// let xs: Vec<f64> = line.points().map(|(x, _, _)| x).collect();
+    /// assert_eq!(xs, [0.0, 1.0, 2.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn points(&self) -> impl Iterator<Item = (f64, f64, f64)> + '_ {
+        (0..self.point_count()).map(|i| self.get_point(i as i32))
     }
 
     /// Get the geometry type ordinal
@@ -301,6 +461,37 @@ impl Geometry {
         }
     }
 
+    /// Returns an iterator over the sub-geometries of this geometry, borrowed from `self`.
+    ///
+    /// These are the members of a collection, such as a multi-polygon, or the rings of a
+    /// polygon, exterior ring first. Other geometry types yield nothing;
+    /// see [`geometry_count`][Self::geometry_count].
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::vector::Geometry;
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let multi = Geometry::from_wkt("MULTIPOINT (0 0,1 1)")?;
+    /// let xs: Vec<f64> = multi.sub_geometries().map(|p| p.get_point(0).0).collect();
+    /// assert_eq!(xs, [0.0, 1.0]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sub_geometries(&self) -> impl Iterator<Item = GeometryRef<'_>> + '_ {
+        (0..self.geometry_count()).map(|i| self.get_geometry(i))
+    }
+
+    /// Add `sub` as a component of this geometry, such as a ring of a polygon or a
+    /// member of a collection. Ownership of `sub` passes to `self`.
+    ///
+    /// The first ring added to a polygon is its exterior ring, the following ones are holes.
+    /// Returns an error if `sub` is of a type that `self` can't hold.
+    ///
+    /// # Panics
+    /// If `sub` is not owned, e.g. it is a view into a feature's geometry.
+    ///
+    /// See: [`OGR_G_AddGeometryDirectly`](https://gdal.org/api/vector_c_api.html#_CPPv425OGR_G_AddGeometryDirectly12OGRGeometryH12OGRGeometryH)
     pub fn add_geometry(&mut self, mut sub: Geometry) -> Result<()> {
         assert!(sub.owned);
         sub.owned = false;
@@ -315,7 +506,7 @@ impl Geometry {
         Ok(())
     }
 
-    /// Compute geometry area in units of the spatial reference system in use.
+    /// Compute geometry length in units of the spatial reference system in use.
     ///
     /// Supported for `Curve` (including `LineString` and `CircularString`) and `MultiCurve`.
     /// Returns zero for all other geometry types.
@@ -335,8 +526,65 @@ impl Geometry {
         unsafe { gdal_sys::OGR_G_Area(self.c_geometry()) }
     }
 
+    /// Compute the shortest 2D distance between `self` and `other`, in units of the
+    /// spatial reference system in use.
+    ///
+    /// Both geometries are assumed to use the same spatial reference system.
+    /// This requires GEOS, and returns an error if GDAL was built without it.
+    ///
+    /// See: [`OGR_G_Distance`](https://gdal.org/api/vector_c_api.html#_CPPv414OGR_G_Distance12OGRGeometryH12OGRGeometryH)
+    pub fn distance(&self, other: &Geometry) -> Result<f64> {
+        let distance = unsafe {
+            gdal_sys::CPLErrorReset();
+            gdal_sys::OGR_G_Distance(self.c_geometry(), other.c_geometry())
+        };
+        if distance < 0.0 {
+            return Err(_last_err_or_ogr_failure("OGR_G_Distance"));
+        }
+        Ok(distance)
+    }
+
+    /// Compute the shortest 3D distance between `self` and `other`.
+    ///
+    /// Like [`distance`][Self::distance], but taking Z coordinates into account.
+    /// Geometries other than points require GDAL to be built with SFCGAL.
+    ///
+    /// See: [`OGR_G_Distance3D`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_Distance3D12OGRGeometryH12OGRGeometryH)
+    pub fn distance_3d(&self, other: &Geometry) -> Result<f64> {
+        let distance = unsafe {
+            gdal_sys::CPLErrorReset();
+            gdal_sys::OGR_G_Distance3D(self.c_geometry(), other.c_geometry())
+        };
+        if distance < 0.0 {
+            return Err(_last_err_or_ogr_failure("OGR_G_Distance3D"));
+        }
+        Ok(distance)
+    }
+
+    /// Compute the centroid of this geometry, as a new point.
+    ///
+    /// The centroid is not necessarily within the geometry. Apart from points,
+    /// this requires GEOS.
+    ///
+    /// See: [`OGR_G_Centroid`](https://gdal.org/api/vector_c_api.html#_CPPv414OGR_G_Centroid12OGRGeometryH12OGRGeometryH)
+    pub fn centroid(&self) -> Result<Geometry> {
+        let centroid = Geometry::empty(OGRwkbGeometryType::wkbPoint)?;
+        let rv = unsafe { gdal_sys::OGR_G_Centroid(self.c_geometry(), centroid.c_geometry()) };
+        if rv != OGRErr::OGRERR_NONE as c_int {
+            return Err(GdalError::OgrError {
+                err: rv as OGRErr::Type,
+                method_name: "OGR_G_Centroid",
+            });
+        }
+        Ok(centroid)
+    }
+
     /// Computes and returns the axis-aligned 2D bounding envelope for this geometry.
     ///
+    /// Empty geometries have no extent; their envelope is degenerate, with all the bounds
+    /// set to `0`. Use [`is_empty`](Self::is_empty) to tell them apart from a geometry
+    /// located at the origin.
+    ///
     /// See: [`OGR_G_GetEnvelope`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_GetEnvelope12OGRGeometryHP11OGREnvelope)
     pub fn envelope(&self) -> Envelope {
         let mut envelope = MaybeUninit::uninit();
@@ -348,6 +596,9 @@ impl Geometry {
 
     /// Computes and returns the axis aligned 3D bounding envelope for this geometry.
     ///
+    /// For 2D geometries the `z` bounds are `0`. Like [`envelope`](Self::envelope),
+    /// all the bounds are `0` for empty geometries.
+    ///
     /// See: [`OGR_G_GetEnvelope3D`](https://gdal.org/api/vector_c_api.html#_CPPv419OGR_G_GetEnvelope3D12OGRGeometryHP13OGREnvelope3D)
     pub fn envelope_3d(&self) -> Envelope3D {
         let mut envelope = MaybeUninit::uninit();
@@ -400,6 +651,29 @@ impl Geometry {
         let p = unsafe { gdal_sys::OGR_G_IsValid(self.c_geometry()) };
         p != 0
     }
+
+    /// Test if the geometry is simple, that is, has no anomalous geometric points
+    /// such as self intersections or self tangencies.
+    ///
+    /// # Notes
+    /// Like [`Self::is_valid`], this requires GEOS and always returns `false` without it.
+    ///
+    /// See: [`OGR_G_IsSimple`](https://gdal.org/api/vector_c_api.html#_CPPv413OGR_G_IsSimple12OGRGeometryH)
+    pub fn is_simple(&self) -> bool {
+        let p = unsafe { gdal_sys::OGR_G_IsSimple(self.c_geometry()) };
+        p != 0
+    }
+
+    /// Test if the geometry is a ring: a closed and simple line string.
+    ///
+    /// # Notes
+    /// Like [`Self::is_valid`], this requires GEOS and always returns `false` without it.
+    ///
+    /// See: [`OGR_G_IsRing`](https://gdal.org/api/vector_c_api.html#_CPPv411OGR_G_IsRing12OGRGeometryH)
+    pub fn is_ring(&self) -> bool {
+        let p = unsafe { gdal_sys::OGR_G_IsRing(self.c_geometry()) };
+        p != 0
+    }
 }
 
 impl Drop for Geometry {
@@ -551,6 +825,103 @@ mod tests {
         assert_eq!(geom.area().floor(), 25.0);
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_envelope() {
+        let wkt = "POLYGON ((1 2, 5 2, 5 7, 3 9, 1 7, 1 2))";
+        let geom = Geometry::from_wkt(wkt).unwrap();
+        let envelope = geom.envelope();
+        assert_eq!(
+            (envelope.MinX, envelope.MinY, envelope.MaxX, envelope.MaxY),
+            (1.0, 2.0, 5.0, 9.0)
+        );
+
+        let geom = Geometry::from_wkt("LINESTRING Z (1 2 -3, 4 -5 6)").unwrap();
+        let envelope = geom.envelope_3d();
+        assert_eq!(
+            (envelope.MinX, envelope.MinY, envelope.MinZ),
+            (1.0, -5.0, -3.0)
+        );
+        assert_eq!(
+            (envelope.MaxX, envelope.MaxY, envelope.MaxZ),
+            (4.0, 2.0, 6.0)
+        );
+
+        let geom = Geometry::empty(wkbPolygon).unwrap();
+        let envelope = geom.envelope();
+        assert!(geom.is_empty());
+        assert_eq!(
+            (envelope.MinX, envelope.MinY, envelope.MaxX, envelope.MaxY),
+            (0.0, 0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_build_triangle() {
+        let mut ring = Geometry::empty(wkbLinearRing).unwrap();
+        for p in [(0.0, 0.0), (4.0, 0.0), (0.0, 3.0), (0.0, 0.0)] {
+            ring.add_point_2d(p);
+        }
+        let mut triangle = Geometry::empty(wkbPolygon).unwrap();
+        triangle.add_geometry(ring).unwrap();
+        assert_eq!(triangle.area(), 6.0);
+        assert_eq!(triangle.wkt().unwrap(), "POLYGON ((0 0,4 0,0 3,0 0))");
+
+        // a point is not a valid polygon ring
+        let point = Geometry::from_wkt("POINT (1 1)").unwrap();
+        assert!(triangle.add_geometry(point).is_err());
+        assert_eq!(triangle.geometry_count(), 1);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_unit_square_measurements() {
+        let square = Geometry::bbox(0.0, 0.0, 1.0, 1.0).unwrap();
+        assert_eq!(square.area(), 1.0);
+        assert_eq!(square.centroid().unwrap().get_point(0), (0.5, 0.5, 0.0));
+
+        let boundary = Geometry::from_wkt("LINESTRING (0 0, 0 1, 1 1, 1 0, 0 0)").unwrap();
+        assert_eq!(boundary.length(), 4.0);
+
+        let point = Geometry::from_wkt("POINT (4 5)").unwrap();
+        assert_eq!(square.distance(&point).unwrap(), 5.0);
+        assert_eq!(point.distance(&square).unwrap(), 5.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_distance_3d() {
+        let a = Geometry::from_wkt("POINT Z (0 0 0)").unwrap();
+        let b = Geometry::from_wkt("POINT Z (2 3 6)").unwrap();
+        assert_eq!(a.distance_3d(&b).unwrap(), 7.0);
+        assert_eq!(a.distance(&b).unwrap(), 13f64.sqrt());
+    }
+
+    #[test]
+    fn test_validity_checks() {
+        let square = Geometry::bbox(0.0, 0.0, 1.0, 1.0).unwrap();
+        assert!(square.is_valid());
+        assert!(square.is_simple());
+        assert!(!square.is_empty());
+
+        let bowtie = Geometry::from_wkt("POLYGON ((0 0, 10 10, 0 10, 10 0, 0 0))").unwrap();
+        assert!(!bowtie.is_valid());
+
+        let ring = Geometry::from_wkt("LINESTRING (0 0, 0 1, 1 1, 1 0, 0 0)").unwrap();
+        assert!(ring.is_simple());
+        assert!(ring.is_ring());
+        let open = Geometry::from_wkt("LINESTRING (0 0, 0 1, 1 1)").unwrap();
+        assert!(open.is_simple());
+        assert!(!open.is_ring());
+        let crossing = Geometry::from_wkt("LINESTRING (0 0, 10 10, 0 10, 10 0)").unwrap();
+        assert!(!crossing.is_simple());
+
+        let empty = Geometry::empty(wkbPolygon).unwrap();
+        assert!(empty.is_empty());
+        assert!(empty.is_valid());
+    }
+
     #[test]
     pub fn test_is_empty() {
         let geom = Geometry::empty(wkbMultiPolygon).unwrap();
@@ -687,6 +1058,22 @@ mod tests {
         assert!(!points.is_empty());
     }
 
+    #[test]
+    fn test_points_iterator() {
+        let line = Geometry::from_wkt("LINESTRING (0 0 1, 1 2 3, 4 5 6)").unwrap();
+        assert_eq!(line.points().count(), line.point_count());
+        let points: Vec<_> = line.points().collect();
+        assert_eq!(points, [(0.0, 0.0, 1.0), (1.0, 2.0, 3.0), (4.0, 5.0, 6.0)]);
+
+        let point = Geometry::from_wkt("POINT (7 8)").unwrap();
+        assert_eq!(point.points().collect::<Vec<_>>(), [(7.0, 8.0, 0.0)]);
+
+        let polygon = Geometry::bbox(0., 0., 1., 1.).unwrap();
+        assert_eq!(polygon.points().count(), 0);
+        let ring = polygon.get_geometry(0);
+        assert_eq!(ring.points().count(), 5);
+    }
+
     #[test]
     fn test_get_points_zm() {
         let mut line = Geometry::empty(wkbLineStringZM).unwrap();
@@ -699,6 +1086,66 @@ mod tests {
         assert_eq!(line_points.get(2), Some(&(1.0, 1.0, 0.5, 1.0)));
     }
 
+    #[test]
+    pub fn test_bulk_points() {
+        let points: Vec<_> = (0..1000)
+            .map(|i| (i as f64, (i * 2) as f64, (i % 7) as f64))
+            .collect();
+        let mut line = Geometry::empty(OGRwkbGeometryType::wkbLineString25D).unwrap();
+        line.set_points(&points).unwrap();
+        assert_eq!(line.point_count(), 1000);
+
+        let mut read = vec![(-1.0, -1.0, -1.0)];
+        assert_eq!(line.get_points(&mut read), 1000);
+        assert_eq!(read[0], (-1.0, -1.0, -1.0));
+        assert_eq!(read[1..], points[..]);
+        for (i, point) in read[1..].iter().enumerate() {
+            assert_eq!(*point, line.get_point(i as i32));
+        }
+
+        let mut zm = Vec::new();
+        assert_eq!(line.get_points_zm(&mut zm), 1000);
+        assert_eq!(zm[999], (999.0, 1998.0, 5.0, 0.0));
+
+        let mut line_2d = Geometry::empty(OGRwkbGeometryType::wkbLineString).unwrap();
+        line_2d.set_points_2d(&[(1.0, 2.0), (3.0, 4.0)]).unwrap();
+        let mut read = Vec::new();
+        line_2d.get_points(&mut read);
+        assert_eq!(read, [(1.0, 2.0, 0.0), (3.0, 4.0, 0.0)]);
+
+        let polygon = Geometry::bbox(0.0, 0.0, 1.0, 1.0).unwrap();
+        assert_eq!(polygon.get_points(&mut read), 0);
+        assert_eq!(read.len(), 2);
+
+        let mut point = Geometry::empty(OGRwkbGeometryType::wkbPoint).unwrap();
+        assert!(point.set_points(&[]).is_err());
+        assert!(point.set_points_2d(&[]).is_err());
+        point.set_points_2d(&[(5.0, 6.0), (7.0, 8.0)]).unwrap();
+        assert_eq!(point.get_point(0), (5.0, 6.0, 0.0));
+    }
+
+    #[test]
+    pub fn test_sub_geometries() {
+        let multi =
+            Geometry::from_wkt("MULTIPOLYGON (((0 0,0 1,1 1,1 0,0 0)),((2 0,2 2,4 2,4 0,2 0)))")
+                .unwrap();
+        assert_eq!(multi.geometry_count(), 2);
+        let areas: Vec<f64> = multi.sub_geometries().map(|p| p.area()).collect();
+        assert_eq!(areas, [1.0, 4.0]);
+
+        let polygon =
+            Geometry::from_wkt("POLYGON ((0 0,0 10,10 10,10 0,0 0),(2 2,2 4,4 4,4 2,2 2))")
+                .unwrap();
+        let rings: Vec<_> = polygon.sub_geometries().collect();
+        assert_eq!(rings.len(), 2);
+        assert_eq!(rings[0].point_count(), 5);
+        assert_eq!(rings[0].get_point(2), (10.0, 10.0, 0.0));
+        assert_eq!(rings[1].get_point(2), (4.0, 4.0, 0.0));
+
+        let point = Geometry::from_wkt("POINT (1 2)").unwrap();
+        assert_eq!(point.sub_geometries().count(), 0);
+    }
+
     #[test]
     pub fn test_geometry_type_to_name() {
         assert_eq!(geometry_type_to_name(wkbLineString), "Line String");
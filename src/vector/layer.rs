@@ -5,7 +5,7 @@ use std::{
     ptr::null_mut,
 };
 
-use gdal_sys::{GDALMajorObjectH, OGRErr, OGRFieldDefnH, OGRFieldType, OGRLayerH};
+use gdal_sys::{CPLErr, GDALMajorObjectH, OGRErr, OGRFieldDefnH, OGRFieldType, OGRLayerH};
 
 use crate::errors::*;
 use crate::metadata::Metadata;
@@ -13,7 +13,7 @@ use crate::spatial_ref::SpatialRef;
 use crate::utils::{_last_null_pointer_err, _string};
 use crate::vector::defn::Defn;
 use crate::vector::feature::{FeatureIterator, OwnedFeatureIterator};
-use crate::vector::{Envelope, Feature, Geometry, LayerOptions};
+use crate::vector::{Envelope, Feature, FieldSubType, Geometry, LayerOptions};
 use crate::{dataset::Dataset, gdal_major_object::MajorObject};
 
 /// Layer capabilities
@@ -57,6 +57,8 @@ pub enum LayerCaps {
     OLCMeasuredGeometries,
     /// Layer capability for a specialized implementation to ArrowArrayStream
     OLCFastGetArrowStream,
+    /// Layer capability for renaming the layer
+    OLCRename,
 }
 
 // Manage conversion to Gdal values
@@ -82,6 +84,7 @@ impl LayerCaps {
             Self::OLCCurveGeometries => "CurveGeometries",
             Self::OLCMeasuredGeometries => "MeasuredGeometries",
             Self::OLCFastGetArrowStream => "FastGetArrowStream",
+            Self::OLCRename => "Rename",
         })
         .unwrap()
     }
@@ -268,6 +271,8 @@ pub trait LayerAccess: Sized {
     }
 
     /// Set a spatial rectangle filter on this layer by specifying the bounds of a rectangle.
+    ///
+    /// See: [OGR_L_SetSpatialFilterRect](https://gdal.org/api/vector_c_api.html#_CPPv426OGR_L_SetSpatialFilterRect9OGRLayerHdddd)
     fn set_spatial_filter_rect(&mut self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) {
         unsafe { gdal_sys::OGR_L_SetSpatialFilterRect(self.c_layer(), min_x, min_y, max_x, max_y) };
     }
@@ -283,6 +288,25 @@ pub trait LayerAccess: Sized {
         _string(rv).unwrap_or_default()
     }
 
+    /// Rename this layer to `new_name`.
+    ///
+    /// Only drivers advertising [`LayerCaps::OLCRename`] support this, and the new
+    /// name must not already be used by another layer of the dataset.
+    ///
+    /// See: [`OGR_L_Rename`](https://gdal.org/api/vector_c_api.html#_CPPv412OGR_L_Rename9OGRLayerHPKc)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_5)))]
+    fn rename(&mut self, new_name: &str) -> Result<()> {
+        let c_name = CString::new(new_name)?;
+        let rv = unsafe { gdal_sys::OGR_L_Rename(self.c_layer(), c_name.as_ptr()) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_Rename",
+            });
+        }
+        Ok(())
+    }
+
     fn has_capability(&self, capability: LayerCaps) -> bool {
         unsafe {
             gdal_sys::OGR_L_TestCapability(self.c_layer(), capability.into_cstring().as_ptr()) == 1
@@ -296,6 +320,18 @@ pub trait LayerAccess: Sized {
         }
         Ok(())
     }
+
+    /// Add a new field to this layer, described by `defn`.
+    ///
+    /// The field is appended to the layer definition, so it is visible through
+    /// [`LayerAccess::defn`] and to features created afterwards. Drivers that can't
+    /// represent the exact field type may approximate it.
+    ///
+    /// See: [`OGR_L_CreateField`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_L_CreateField9OGRLayerH12OGRFieldDefnHi)
+    fn create_field(&mut self, defn: &FieldDefn) -> Result<()> {
+        defn.add_to_layer(self)
+    }
+
     fn create_feature(&mut self, geometry: Geometry) -> Result<()> {
         let feature = Feature::new(self.defn())?;
 
@@ -317,11 +353,83 @@ pub trait LayerAccess: Sized {
         Ok(())
     }
 
+    /// Adds all `features` to this layer inside a single layer transaction, returning
+    /// how many were written.
+    ///
+    /// If any feature can't be created, the transaction is rolled back and the error
+    /// is returned. Batching the inserts this way makes bulk loads into transactional
+    /// drivers such as GeoPackage, SQLite or PostgreSQL much faster than one implicit
+    /// transaction per feature.
+    ///
+    /// Drivers without transaction support, e.g. Shapefile, GeoJSON or Memory, accept
+    /// the transaction calls without doing anything. With those, the features written
+    /// before a failure are kept; check [`LayerCaps::OLCTransactions`] to find out
+    /// whether the layer can roll back.
+    ///
+    /// The features must have been created from a definition matching this layer, e.g.
+    /// one obtained with [`Defn::from_layer`].
+    ///
+    /// Layer transactions don't nest with dataset transactions: while a
+    /// [`Dataset::start_transaction`](crate::Dataset::start_transaction) guard is active,
+    /// drivers that implement layer transactions on top of the dataset one, such as the
+    /// SQLite based ones, refuse `OGR_L_StartTransaction`, and this returns that error
+    /// before writing anything. Inside such a guard, create the features one by one with
+    /// [`Feature::create`] and let the guard commit them.
+    ///
+    /// If rolling back fails as well, the rollback error is reported as a warning through
+    /// the GDAL error handler (see [`config::set_error_handler`](crate::config::set_error_handler)),
+    /// and the error of the failed feature is returned.
+    ///
+    /// See: [`OGR_L_StartTransaction`](https://gdal.org/api/vector_c_api.html#_CPPv422OGR_L_StartTransaction9OGRLayerH)
+    fn create_features<'f, I: IntoIterator<Item = Feature<'f>>>(
+        &mut self,
+        features: I,
+    ) -> Result<usize> {
+        let rv = unsafe { gdal_sys::OGR_L_StartTransaction(self.c_layer()) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_StartTransaction",
+            });
+        }
+
+        let mut count = 0;
+        for feature in features {
+            if let Err(e) = feature.create(self) {
+                let rv = unsafe { gdal_sys::OGR_L_RollbackTransaction(self.c_layer()) };
+                if rv != OGRErr::OGRERR_NONE {
+                    let msg = format!("OGR_L_RollbackTransaction returned error {rv} after: {e}");
+                    let msg = CString::new(msg).unwrap_or_default();
+                    unsafe {
+                        gdal_sys::CPLError(
+                            CPLErr::CE_Warning,
+                            error_num::APP_DEFINED,
+                            c"%s".as_ptr(),
+                            msg.as_ptr(),
+                        )
+                    };
+                }
+                return Err(e);
+            }
+            count += 1;
+        }
+
+        let rv = unsafe { gdal_sys::OGR_L_CommitTransaction(self.c_layer()) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_CommitTransaction",
+            });
+        }
+        Ok(count)
+    }
+
     /// Returns the number of features in this layer, even if it requires expensive calculation.
     ///
     /// Some drivers will actually scan the entire layer once to count objects.
     ///
-    /// The returned count takes the [spatial filter](`Layer::set_spatial_filter`) into account.
+    /// The returned count takes the [spatial filter](`Layer::set_spatial_filter`) and
+    /// [attribute filter](`Layer::set_attribute_filter`) into account.
     /// For dynamic databases the count may not be exact.
     fn feature_count(&self) -> u64 {
         (unsafe { gdal_sys::OGR_L_GetFeatureCount(self.c_layer(), 1) }) as u64
@@ -333,7 +441,8 @@ pub trait LayerAccess: Sized {
     /// For some drivers, it would be expensive to establish the feature count, in which case
     /// [`None`] will be returned.
     ///
-    /// The returned count takes the [spatial filter](`Layer::set_spatial_filter`) into account.
+    /// The returned count takes the [spatial filter](`Layer::set_spatial_filter`) and
+    /// [attribute filter](`Layer::set_attribute_filter`) into account.
     /// For dynamic databases the count may not be exact.
     fn try_feature_count(&self) -> Option<u64> {
         let rv = unsafe { gdal_sys::OGR_L_GetFeatureCount(self.c_layer(), 0) };
@@ -394,6 +503,27 @@ pub trait LayerAccess: Sized {
         }
     }
 
+    /// Returns the 3D extent of the first geometry field of this layer, even if it requires
+    /// expensive calculation.
+    ///
+    /// The same caveats about the spatial filter as for [`get_extent`](Self::get_extent) apply.
+    ///
+    /// See: [OGR_L_GetExtent3D](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_L_GetExtent3D9OGRLayerHiP13OGREnvelope3Di)
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    fn get_extent_3d(&self) -> Result<crate::vector::Envelope3D> {
+        let mut envelope = MaybeUninit::uninit();
+        let force = 1;
+        let rv =
+            unsafe { gdal_sys::OGR_L_GetExtent3D(self.c_layer(), 0, envelope.as_mut_ptr(), force) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_GetExtent3D",
+            });
+        }
+        Ok(unsafe { envelope.assume_init() })
+    }
+
     /// Get the spatial reference system for this layer.
     ///
     /// Returns `Some(SpatialRef)`, or `None` if one isn't defined.
@@ -419,7 +549,10 @@ pub trait LayerAccess: Sized {
     /// From the GDAL docs: Note that installing a query string will generally result in resetting the current reading position
     ///
     /// Parameters:
-    /// - `query` in restricted SQL WHERE format
+    /// - `query` in restricted SQL WHERE format. An empty query clears the filter,
+    ///   like [`clear_attribute_filter`](Self::clear_attribute_filter).
+    ///
+    /// Fails if `query` can't be parsed.
     ///
     fn set_attribute_filter(&mut self, query: &str) -> Result<()> {
         let c_str = CString::new(query)?;
@@ -453,6 +586,8 @@ pub trait LayerAccess: Sized {
     ///
     /// * `INCLUDE_FID=YES/NO`. Whether to include the FID column. Defaults to YES.
     /// * `MAX_FEATURES_IN_BATCH=integer`. Maximum number of features to retrieve in a ArrowArray batch. Defaults to 65 536.
+    /// * `GEOMETRY_ENCODING=WKB`. Encode geometries as WKB binary columns, which is the default.
+    ///   Recent GDAL versions also accept GeoArrow encodings here.
     ///
     /// Additional driver-specific options may exist.
     ///
@@ -486,6 +621,154 @@ pub trait LayerAccess: Sized {
 
         Ok(())
     }
+
+    /// Write all batches of an [Arrow](https://arrow.apache.org/) stream to this layer.
+    ///
+    /// The stream is consumed: it is released once all batches have been written, or on error.
+    ///
+    /// If the layer has no attribute fields yet, they are first created from the stream schema,
+    /// skipping the geometry and FID columns. Otherwise, columns are matched to the existing
+    /// fields by name.
+    ///
+    /// Geometry columns are recognized by an `ARROW:extension:name` metadata of `ogc.wkb` or
+    /// `geoarrow.wkb`. As defined in the OGR documentation for [`WriteArrowBatch`](https://gdal.org/api/ogrlayer_cpp.html#_CPPv4N8OGRLayer15WriteArrowBatchEPK11ArrowSchemaP10ArrowArray12CSLConstList),
+    /// the current options are:
+    ///
+    /// * `FID=name`. Name of the FID column in the schema.
+    /// * `GEOMETRY_NAME=name`. Name of the geometry column in the schema.
+    /// * `IF_FID_NOT_PRESERVED=NOTHING/ERROR/WARNING`. What to do when the FIDs of the
+    ///   stream cannot be preserved. Defaults to NOTHING.
+    ///
+    /// If the layer cannot store the stream schema, returns [`GdalError::BadArgument`] with the
+    /// reason given by GDAL.
+    ///
+    /// This API is new as of GDAL 3.8.
+    ///
+    /// # Safety
+    /// This uses the Arrow C Data Interface to operate on raw pointers provisioned from Rust.
+    /// `stream` must point to a valid ArrowArrayStream, as defined by the ArrowArrayStream spec.
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_8)))]
+    unsafe fn write_arrow_stream(
+        &mut self,
+        stream: *mut gdal_sys::ArrowArrayStream,
+        options: &crate::cpl::CslStringList,
+    ) -> Result<()> {
+        let result = unsafe { _write_arrow_stream(self.c_layer(), stream, options) };
+        unsafe {
+            if let Some(release) = (*stream).release {
+                release(stream);
+            }
+        }
+        result
+    }
+}
+
+/// Returns the last error message of an Arrow stream, for a non-zero `rv`.
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_8)))]
+unsafe fn _arrow_stream_err(stream: *mut gdal_sys::ArrowArrayStream, rv: c_int) -> GdalError {
+    let msg = unsafe { (*stream).get_last_error.and_then(|f| _string(f(stream))) };
+    GdalError::BadArgument(msg.unwrap_or_else(|| format!("Arrow stream error {rv}")))
+}
+
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_8)))]
+unsafe fn _write_arrow_stream(
+    c_layer: OGRLayerH,
+    stream: *mut gdal_sys::ArrowArrayStream,
+    options: &crate::cpl::CslStringList,
+) -> Result<()> {
+    let Some(get_schema) = (unsafe { (*stream).get_schema }) else {
+        return Err(GdalError::BadArgument(
+            "Arrow stream has no get_schema callback".to_string(),
+        ));
+    };
+    let mut schema = unsafe { std::mem::zeroed::<gdal_sys::ArrowSchema>() };
+    let rv = unsafe { get_schema(stream, &mut schema) };
+    if rv != 0 {
+        return Err(unsafe { _arrow_stream_err(stream, rv) });
+    }
+
+    let result = unsafe { _write_arrow_batches(c_layer, stream, &schema, options) };
+    if let Some(release) = schema.release {
+        unsafe { release(&mut schema) };
+    }
+    result
+}
+
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_8)))]
+unsafe fn _write_arrow_batches(
+    c_layer: OGRLayerH,
+    stream: *mut gdal_sys::ArrowArrayStream,
+    schema: &gdal_sys::ArrowSchema,
+    options: &crate::cpl::CslStringList,
+) -> Result<()> {
+    let c_options = options.as_ptr();
+
+    let mut c_msg: *mut c_char = null_mut();
+    let supported =
+        unsafe { gdal_sys::OGR_L_IsArrowSchemaSupported(c_layer, schema, c_options, &mut c_msg) };
+    if !supported {
+        let msg = _string(c_msg).unwrap_or_default();
+        unsafe { gdal_sys::VSIFree(c_msg.cast()) };
+        return Err(GdalError::BadArgument(msg));
+    }
+
+    let c_defn = unsafe { gdal_sys::OGR_L_GetLayerDefn(c_layer) };
+    if unsafe { gdal_sys::OGR_FD_GetFieldCount(c_defn) } == 0 {
+        let geometry_name = options
+            .fetch_name_value("GEOMETRY_NAME")
+            .unwrap_or_else(|| {
+                _string(unsafe { gdal_sys::OGR_L_GetGeometryColumn(c_layer) })
+                    .filter(|name| !name.is_empty())
+                    .unwrap_or_else(|| "wkb_geometry".to_string())
+            });
+        let fid_name = options
+            .fetch_name_value("FID")
+            .or_else(|| _string(unsafe { gdal_sys::OGR_L_GetFIDColumn(c_layer) }))
+            .unwrap_or_default();
+
+        for i in 0..schema.n_children as usize {
+            let child = unsafe { *schema.children.add(i) };
+            let name = _string(unsafe { (*child).name }).unwrap_or_default();
+            if name == geometry_name || name == fid_name {
+                continue;
+            }
+            if !unsafe { gdal_sys::OGR_L_CreateFieldFromArrowSchema(c_layer, child, c_options) } {
+                return Err(GdalError::OgrError {
+                    err: OGRErr::OGRERR_FAILURE,
+                    method_name: "OGR_L_CreateFieldFromArrowSchema",
+                });
+            }
+        }
+    }
+
+    let Some(get_next) = (unsafe { (*stream).get_next }) else {
+        return Err(GdalError::BadArgument(
+            "Arrow stream has no get_next callback".to_string(),
+        ));
+    };
+    loop {
+        let mut array = unsafe { std::mem::zeroed::<gdal_sys::ArrowArray>() };
+        let rv = unsafe { get_next(stream, &mut array) };
+        if rv != 0 {
+            return Err(unsafe { _arrow_stream_err(stream, rv) });
+        }
+        // A released array marks the end of the stream
+        if array.release.is_none() {
+            return Ok(());
+        }
+
+        let success =
+            unsafe { gdal_sys::OGR_L_WriteArrowBatch(c_layer, schema, &mut array, c_options) };
+        if let Some(release) = array.release {
+            unsafe { release(&mut array) };
+        }
+        if !success {
+            return Err(GdalError::OgrError {
+                err: OGRErr::OGRERR_FAILURE,
+                method_name: "OGR_L_WriteArrowBatch",
+            });
+        }
+    }
 }
 
 pub struct LayerIterator<'a> {
@@ -558,12 +841,37 @@ impl FieldDefn {
     pub fn set_precision(&self, precision: i32) {
         unsafe { gdal_sys::OGR_Fld_SetPrecision(self.c_obj, precision as c_int) };
     }
+    /// Set the subtype of this field, which refines its type, e.g. a boolean
+    /// stored in an [`OGRFieldType::OFTInteger`] field.
+    pub fn set_subtype(&self, subtype: FieldSubType) {
+        unsafe { gdal_sys::OGR_Fld_SetSubType(self.c_obj, subtype.c_int()) };
+    }
+    /// Get the subtype of this field.
+    pub fn subtype(&self) -> FieldSubType {
+        FieldSubType::from_c_int(unsafe { gdal_sys::OGR_Fld_GetSubType(self.c_obj) })
+    }
+    /// Set the formatting width of this field. Zero means no specified width.
+    pub fn with_width(&self, width: i32) -> &Self {
+        self.set_width(width);
+        self
+    }
+    /// Set the formatting precision of this field, the number of digits after
+    /// the decimal point for [`OGRFieldType::OFTReal`] fields.
+    pub fn with_precision(&self, precision: i32) -> &Self {
+        self.set_precision(precision);
+        self
+    }
+    /// Set the subtype of this field. See [`FieldDefn::set_subtype`].
+    pub fn with_subtype(&self, subtype: FieldSubType) -> &Self {
+        self.set_subtype(subtype);
+        self
+    }
     pub fn add_to_layer<L: LayerAccess>(&self, layer: &L) -> Result<()> {
         let rv = unsafe { gdal_sys::OGR_L_CreateField(layer.c_layer(), self.c_obj, 1) };
         if rv != OGRErr::OGRERR_NONE {
             return Err(GdalError::OgrError {
                 err: rv,
-                method_name: "OGR_L_CreateFeature",
+                method_name: "OGR_L_CreateField",
             });
         }
         Ok(())
@@ -581,6 +889,9 @@ impl Dataset {
     }
 
     /// Get the number of layers in this dataset.
+    ///
+    /// This is `0` for raster-only datasets. Some formats, such as GeoPackage,
+    /// can hold both raster bands and vector layers in the same dataset.
     pub fn layer_count(&self) -> usize {
         (unsafe { gdal_sys::GDALDatasetGetLayerCount(self.c_dataset()) }) as usize
     }
@@ -641,6 +952,10 @@ impl Dataset {
     /// Creates a new layer. The [`LayerOptions`] struct implements `Default`, so you only need to
     /// specify those options that deviate from the default.
     ///
+    /// The returned [`Layer`] borrows the dataset mutably, so it has to go out of scope before
+    /// the dataset can be used again, e.g. to create a second layer. It can be fetched again
+    /// later with [`Dataset::layer_by_name`].
+    ///
     /// # Examples
     ///
     /// Create a new layer with an empty name, no spatial reference, and unknown geometry type:
@@ -742,7 +1057,10 @@ mod tests {
     use super::{LayerCaps::*, *};
     use crate::options::DatasetOptions;
     use crate::spatial_ref::AxisMappingStrategy;
-    use crate::test_utils::{fixture, open_gpkg_for_update, SuppressGDALErrorLog, TempFixture};
+    use crate::test_utils::{
+        fixture, memory_dataset, memory_layer, open_gpkg_for_update, InMemoryFixture,
+        SuppressGDALErrorLog, TempFixture,
+    };
     use crate::vector::feature::FeatureIterator;
     use crate::vector::FieldValue;
     use crate::{assert_almost_eq, Dataset, DriverManager, GdalOpenFlags};
@@ -804,6 +1122,215 @@ mod tests {
         assert!(ds.create_layer(options).is_ok());
     }
 
+    #[test]
+    fn test_create_layer_persists() -> Result<()> {
+        let output = InMemoryFixture::new("create_layer.gpkg");
+        {
+            let driver = DriverManager::get_driver_by_name("GPKG")?;
+            let mut ds = driver.create_vector_only(output.path())?;
+            let srs = SpatialRef::from_epsg(4326)?;
+            let mut layer = ds.create_layer(LayerOptions {
+                name: "sites",
+                srs: Some(&srs),
+                ty: OGRwkbGeometryType::wkbPoint,
+                options: Some(&["GEOMETRY_NAME=location"]),
+            })?;
+            layer.create_feature(Geometry::from_wkt("POINT (1 2)")?)?;
+        }
+
+        let ds = Dataset::open(output.path())?;
+        let mut layer = ds.layer_by_name("sites")?;
+        assert_eq!(layer.feature_count(), 1);
+        let geom_field = layer.defn().geom_fields().next().unwrap();
+        assert_eq!(geom_field.name(), "location");
+        assert_eq!(geom_field.field_type(), OGRwkbGeometryType::wkbPoint);
+        assert_eq!(layer.spatial_ref().unwrap().auth_code()?, 4326);
+        let feature = layer.features().next().unwrap();
+        assert_eq!(feature.geometry().unwrap().wkt()?, "POINT (1 2)");
+        Ok(())
+    }
+
+    #[test]
+    fn test_delete_and_rename_layer() -> Result<()> {
+        let output = InMemoryFixture::new("delete_layer.gpkg");
+        let driver = DriverManager::get_driver_by_name("GPKG")?;
+        let mut ds = driver.create_vector_only(output.path())?;
+        for name in ["first", "second"] {
+            ds.create_layer(LayerOptions {
+                name,
+                ty: OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })?;
+        }
+        assert_eq!(ds.layer_count(), 2);
+
+        ds.delete_layer(0)?;
+        assert_eq!(ds.layer_count(), 1);
+        assert_eq!(ds.layer(0)?.name(), "second");
+        assert!(ds.layer_by_name("first").is_err());
+
+        #[cfg(any(major_ge_4, all(major_is_3, minor_ge_5)))]
+        {
+            let mut layer = ds.layer(0)?;
+            assert!(layer.has_capability(OLCRename));
+            layer.rename("renamed")?;
+            assert_eq!(layer.name(), "renamed");
+            assert_eq!(ds.layer_by_name("renamed")?.name(), "renamed");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_features() -> Result<()> {
+        let output = InMemoryFixture::new("create_features.gpkg");
+        {
+            let driver = DriverManager::get_driver_by_name("GPKG")?;
+            let mut ds = driver.create_vector_only(output.path())?;
+            let mut layer = ds.create_layer(LayerOptions {
+                name: "points",
+                ty: OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })?;
+            layer.create_defn_fields(&[("id", OGRFieldType::OFTInteger)])?;
+            assert!(layer.has_capability(OLCTransactions));
+
+            let defn = Defn::from_layer(&layer);
+            let features = (0..1000).map(|i| {
+                let mut feature = Feature::new(&defn).unwrap();
+                feature
+                    .set_geometry(Geometry::from_wkt(&format!("POINT ({i} {i})")).unwrap())
+                    .unwrap();
+                feature.set_field_integer(0, i).unwrap();
+                feature
+            });
+            assert_eq!(layer.create_features(features)?, 1000);
+        }
+
+        let ds = Dataset::open(output.path())?;
+        let layer = ds.layer_by_name("points")?;
+        assert_eq!(layer.feature_count(), 1000);
+        Ok(())
+    }
+
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_6)))]
+    #[test]
+    fn test_read_arrow_stream_batch() -> Result<()> {
+        use arrow::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+        use arrow::record_batch::RecordBatchReader;
+
+        let mut ds = memory_dataset()?;
+        let mut layer = memory_layer(&mut ds, "points", OGRwkbGeometryType::wkbPoint, &[])?;
+        for i in 0..25 {
+            layer.create_feature(Geometry::from_wkt(&format!("POINT ({i} {i})"))?)?;
+        }
+
+        let mut options = crate::cpl::CslStringList::new();
+        options.set_name_value("MAX_FEATURES_IN_BATCH", "10")?;
+        options.set_name_value("GEOMETRY_ENCODING", "WKB")?;
+
+        let mut stream = FFI_ArrowArrayStream::empty();
+        let stream_ptr: *mut crate::ArrowArrayStream =
+            (&mut stream as *mut FFI_ArrowArrayStream).cast();
+        unsafe { layer.read_arrow_stream(stream_ptr, &options)? };
+
+        let mut reader = ArrowArrayStreamReader::try_new(stream).unwrap();
+        assert!(reader.schema().column_with_name("wkb_geometry").is_some());
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 10);
+        let total: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+        assert_eq!(total, 15);
+        Ok(())
+    }
+
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_8)))]
+    #[test]
+    fn test_write_arrow_stream() -> Result<()> {
+        use crate::cpl::CslStringList;
+        use arrow::array::{BinaryArray, RecordBatch, RecordBatchIterator, StringArray};
+        use arrow::datatypes::{DataType, Field, Schema};
+        use arrow::ffi_stream::FFI_ArrowArrayStream;
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        let wkb = ["POINT (1 2)", "POINT (3 4)", "POINT (5 6)"]
+            .iter()
+            .map(|wkt| Geometry::from_wkt(wkt)?.wkb())
+            .collect::<Result<Vec<_>>>()?;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("name", DataType::Utf8, false),
+            Field::new("geom", DataType::Binary, false).with_metadata(HashMap::from([(
+                "ARROW:extension:name".to_string(),
+                "ogc.wkb".to_string(),
+            )])),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(StringArray::from(vec!["a", "b", "c"])),
+                Arc::new(BinaryArray::from_iter_values(wkb.iter())),
+            ],
+        )
+        .unwrap();
+        let reader = RecordBatchIterator::new(vec![Ok(batch)], schema);
+        let mut stream = FFI_ArrowArrayStream::new(Box::new(reader));
+
+        let output = InMemoryFixture::new("write_arrow_stream.gpkg");
+        {
+            let driver = DriverManager::get_driver_by_name("GPKG")?;
+            let mut ds = driver.create_vector_only(output.path())?;
+            let mut layer = ds.create_layer(LayerOptions {
+                name: "points",
+                ty: OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })?;
+            let mut options = CslStringList::new();
+            options.set_name_value("GEOMETRY_NAME", "geom")?;
+            let stream_ptr: *mut crate::ArrowArrayStream =
+                (&mut stream as *mut FFI_ArrowArrayStream).cast();
+            unsafe { layer.write_arrow_stream(stream_ptr, &options)? };
+        }
+
+        let ds = Dataset::open(output.path())?;
+        let mut layer = ds.layer_by_name("points")?;
+        assert_eq!(layer.feature_count(), 3);
+        let feature = layer.features().last().unwrap();
+        let name_idx = feature.field_index("name")?;
+        assert_eq!(feature.field_as_string(name_idx)?.unwrap(), "c");
+        assert_eq!(feature.geometry().unwrap().wkt()?, "POINT (5 6)");
+        Ok(())
+    }
+
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_8)))]
+    #[test]
+    fn test_write_arrow_stream_unsupported_schema() -> Result<()> {
+        use crate::cpl::CslStringList;
+        use arrow::array::{new_null_array, RecordBatch, RecordBatchIterator};
+        use arrow::datatypes::{DataType, Field, IntervalUnit, Schema};
+        use arrow::ffi_stream::FFI_ArrowArrayStream;
+        use std::sync::Arc;
+
+        let _nolog = SuppressGDALErrorLog::new();
+        // GDAL has no field type for intervals
+        let interval = DataType::Interval(IntervalUnit::MonthDayNano);
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "period",
+            interval.clone(),
+            true,
+        )]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![new_null_array(&interval, 1)]).unwrap();
+        let reader = RecordBatchIterator::new(vec![Ok(batch)], schema);
+        let mut stream = FFI_ArrowArrayStream::new(Box::new(reader));
+
+        let mut ds = memory_dataset()?;
+        let mut layer = memory_layer(&mut ds, "", OGRwkbGeometryType::wkbUnknown, &[])?;
+        let stream_ptr: *mut crate::ArrowArrayStream =
+            (&mut stream as *mut FFI_ArrowArrayStream).cast();
+        let result = unsafe { layer.write_arrow_stream(stream_ptr, &CslStringList::new()) };
+        assert!(matches!(result, Err(GdalError::BadArgument(_))));
+        Ok(())
+    }
+
     #[test]
     fn test_layer_count() {
         let ds = Dataset::open(fixture("roads.geojson")).unwrap();
@@ -827,6 +1354,41 @@ mod tests {
         assert_almost_eq(extent.MaxY, 44.431818);
     }
 
+    fn point_z_layer(ds: &mut Dataset) -> Layer<'_> {
+        let mut layer = memory_layer(ds, "points", OGRwkbGeometryType::wkbPoint25D, &[]).unwrap();
+        for wkt in ["POINT Z (1 2 3)", "POINT Z (-4 5 -6)", "POINT Z (7 -8 9)"] {
+            layer
+                .create_feature(Geometry::from_wkt(wkt).unwrap())
+                .unwrap();
+        }
+        layer
+    }
+
+    #[test]
+    fn test_layer_get_extent_points() {
+        let mut ds = memory_dataset().unwrap();
+        let layer = point_z_layer(&mut ds);
+        let extent = layer.get_extent().unwrap();
+        assert_almost_eq(extent.MinX, -4.0);
+        assert_almost_eq(extent.MaxX, 7.0);
+        assert_almost_eq(extent.MinY, -8.0);
+        assert_almost_eq(extent.MaxY, 5.0);
+    }
+
+    #[test]
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    fn test_layer_get_extent_3d() {
+        let mut ds = memory_dataset().unwrap();
+        let layer = point_z_layer(&mut ds);
+        let extent = layer.get_extent_3d().unwrap();
+        assert_almost_eq(extent.MinX, -4.0);
+        assert_almost_eq(extent.MaxX, 7.0);
+        assert_almost_eq(extent.MinY, -8.0);
+        assert_almost_eq(extent.MaxY, 5.0);
+        assert_almost_eq(extent.MinZ, -6.0);
+        assert_almost_eq(extent.MaxZ, 9.0);
+    }
+
     #[test]
     fn test_layer_try_get_extent() {
         let ds = Dataset::open(fixture("roads.geojson")).unwrap();
@@ -883,6 +1445,18 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_feature_count_with_filter() {
+        with_layer("roads.geojson", |mut layer| {
+            layer
+                .set_attribute_filter("highway = 'residential'")
+                .unwrap();
+            let count = layer.feature_count();
+            assert_eq!(count, 2);
+            assert_eq!(count, layer.features().count() as u64);
+        });
+    }
+
     #[test]
     fn test_feature() {
         with_layer("roads.geojson", |layer| {
@@ -1167,6 +1741,179 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_set_field_datetime() -> Result<()> {
+        use chrono::{DateTime, FixedOffset, TimeZone};
+
+        let mut ds = memory_dataset()?;
+        let fields = [("ts", OGRFieldType::OFTDateTime)];
+        let layer = memory_layer(&mut ds, "", OGRwkbGeometryType::wkbUnknown, &fields)?;
+
+        let local = DateTime::parse_from_rfc3339("2024-03-05T12:34:56.789+05:30").unwrap();
+        let utc = FixedOffset::east_opt(0)
+            .unwrap()
+            .with_ymd_and_hms(2001, 2, 3, 4, 5, 6)
+            .unwrap();
+        for value in [local, utc] {
+            let mut feature = Feature::new(layer.defn())?;
+            feature.set_field_datetime(0, value)?;
+            let read = feature.field_as_datetime(0)?.unwrap();
+            assert_eq!(read, value);
+            assert_eq!(read.offset(), value.offset());
+        }
+
+        let mut feature = Feature::new(layer.defn())?;
+        feature.set_field_datetime(0, utc)?;
+        assert_eq!(
+            feature.field_as_string(0)?.as_deref(),
+            Some("2001/02/03 04:05:06+00")
+        );
+
+        // rounding up to a whole minute and leap seconds carry into the next minute
+        let next_minute = FixedOffset::east_opt(0)
+            .unwrap()
+            .with_ymd_and_hms(2001, 2, 3, 4, 6, 0)
+            .unwrap();
+        for second in [59.9996, 60.0] {
+            unsafe {
+                gdal_sys::OGR_F_SetFieldDateTimeEx(
+                    feature.c_feature(),
+                    0,
+                    2001,
+                    2,
+                    3,
+                    4,
+                    5,
+                    second,
+                    100,
+                )
+            };
+            assert_eq!(feature.field_as_datetime(0)?, Some(next_minute));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_fields_round_trip() -> Result<()> {
+        // GeoJSON keeps list types, while e.g. GeoPackage stores them as JSON strings
+        let output = InMemoryFixture::new("list_fields.geojson");
+        {
+            let driver = DriverManager::get_driver_by_name("GeoJSON")?;
+            let mut ds = driver.create_vector_only(output.path())?;
+            let layer = ds.create_layer(LayerOptions {
+                name: "lists",
+                ty: OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })?;
+            layer.create_defn_fields(&[
+                ("ints", OGRFieldType::OFTIntegerList),
+                ("reals", OGRFieldType::OFTRealList),
+                ("names", OGRFieldType::OFTStringList),
+                ("empty", OGRFieldType::OFTIntegerList),
+            ])?;
+            let mut feature = Feature::new(layer.defn())?;
+            feature.set_geometry(Geometry::from_wkt("POINT (1 2)")?)?;
+            feature.set_field_integer_list(0, &[3, -1, 4, 1, 5])?;
+            feature.set_field_double_list(1, &[0.5, 2.25])?;
+            feature.set_field_string_list(2, &["a", "b c"])?;
+            feature.set_field_integer_list(3, &[])?;
+            feature.create(&layer)?;
+        }
+
+        let ds = Dataset::open(output.path())?;
+        let mut layer = ds.layer(0)?;
+        let feature = layer.features().next().unwrap();
+        assert_eq!(
+            feature.field_as_integer_list(0)?,
+            Some(vec![3, -1, 4, 1, 5])
+        );
+        assert_eq!(feature.field_as_double_list(1)?, Some(vec![0.5, 2.25]));
+        assert_eq!(
+            feature.field_as_string_list(2)?,
+            Some(vec!["a".to_string(), "b c".to_string()])
+        );
+        assert_eq!(
+            feature.field_as_integer_list(3)?.unwrap_or_default(),
+            vec![]
+        );
+        assert!(matches!(
+            feature.field_as_integer_list(4),
+            Err(GdalError::InvalidFieldIndex {
+                index: 4,
+                method_name: "field_as_integer_list",
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_field_null_and_set_states() -> Result<()> {
+        let mut ds = memory_dataset()?;
+        let fields = [("name", OGRFieldType::OFTString)];
+        let layer = memory_layer(&mut ds, "", OGRwkbGeometryType::wkbUnknown, &fields)?;
+        let mut feature = Feature::new(layer.defn())?;
+
+        // a new feature starts out unset, which is not null
+        assert!(!feature.is_field_set(0)?);
+        assert!(!feature.is_field_null(0)?);
+
+        feature.set_field_string(0, "")?;
+        assert!(feature.is_field_set(0)?);
+        assert!(!feature.is_field_null(0)?);
+        assert_eq!(feature.field_as_string(0)?, Some(String::new()));
+
+        feature.set_field_null(0)?;
+        assert!(feature.is_field_set(0)?);
+        assert!(feature.is_field_null(0)?);
+        assert_eq!(feature.field_as_string(0)?, None);
+
+        feature.unset_field(0)?;
+        assert!(!feature.is_field_set(0)?);
+        assert!(!feature.is_field_null(0)?);
+
+        assert!(matches!(
+            feature.is_field_null(1),
+            Err(GdalError::InvalidFieldIndex {
+                index: 1,
+                method_name: "is_field_null",
+            })
+        ));
+        assert!(matches!(
+            feature.is_field_set(1),
+            Err(GdalError::InvalidFieldIndex {
+                index: 1,
+                method_name: "is_field_set",
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_field() -> Result<()> {
+        let mut ds = memory_dataset()?;
+        let fields = [("blob", OGRFieldType::OFTBinary)];
+        let layer = memory_layer(&mut ds, "", OGRwkbGeometryType::wkbUnknown, &fields)?;
+
+        let blob: Vec<u8> = (0..16).map(|i| i * 17).collect();
+        let mut feature = Feature::new(layer.defn())?;
+        feature.set_field_null(0)?;
+        assert_eq!(feature.field_as_binary(0)?, None);
+        feature.set_field_binary(0, &blob)?;
+        assert_eq!(feature.field_as_binary(0)?, Some(blob));
+
+        feature.set_field_binary(0, &[])?;
+        assert_eq!(feature.field_as_binary(0)?, Some(Vec::new()));
+
+        assert!(matches!(
+            feature.field_as_binary(1),
+            Err(GdalError::InvalidFieldIndex {
+                index: 1,
+                method_name: "field_as_binary",
+            })
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_field_in_layer() {
         ds_with_layer("three_layer_ds.s3db", "layer_0", |mut layer| {
@@ -1430,6 +2177,44 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_set_attribute_filter_numeric() {
+        let mut ds = memory_dataset().unwrap();
+        let fields = [
+            ("name", OGRFieldType::OFTString),
+            ("population", OGRFieldType::OFTInteger64),
+        ];
+        let mut layer =
+            memory_layer(&mut ds, "cities", OGRwkbGeometryType::wkbPoint, &fields).unwrap();
+        let name_idx = layer.defn().field_index("name").unwrap();
+        let population_idx = layer.defn().field_index("population").unwrap();
+        for (name, population) in [
+            ("Bucharest", 1_716_961),
+            ("Cluj-Napoca", 286_598),
+            ("Iasi", 271_692),
+            ("Madrid", 3_332_035),
+        ] {
+            let mut feature = Feature::new(layer.defn()).unwrap();
+            feature.set_field_string(name_idx, name).unwrap();
+            feature
+                .set_field_integer64(population_idx, population)
+                .unwrap();
+            feature.create(&layer).unwrap();
+        }
+
+        layer.set_attribute_filter("population > 1000000").unwrap();
+        let mut names = layer
+            .features()
+            .map(|f| f.field_as_string(name_idx).unwrap().unwrap())
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, vec!["Bucharest", "Madrid"]);
+
+        // An empty query clears the filter.
+        layer.set_attribute_filter("").unwrap();
+        assert_eq!(layer.features().count(), 4);
+    }
+
     #[test]
     fn test_set_feature() {
         let ds_options = DatasetOptions {
@@ -1459,6 +2244,86 @@ mod tests {
             .unwrap();
         assert_eq!(value, 1);
     }
+    #[test]
+    fn test_create_field() -> Result<()> {
+        let mut ds = memory_dataset()?;
+        let mut layer = memory_layer(&mut ds, "fields", OGRwkbGeometryType::wkbPoint, &[])?;
+
+        layer
+            .create_field(FieldDefn::new("population", OGRFieldType::OFTInteger)?.with_width(10))?;
+        layer.create_field(
+            FieldDefn::new("name", OGRFieldType::OFTString)?
+                .with_width(32)
+                .with_precision(0),
+        )?;
+        layer.create_field(
+            FieldDefn::new("visited", OGRFieldType::OFTInteger)?
+                .with_subtype(FieldSubType::Boolean),
+        )?;
+
+        let fields = layer
+            .defn()
+            .fields()
+            .map(|f| (f.name(), f.field_type(), f.width()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![
+                ("population".to_string(), OGRFieldType::OFTInteger, 10),
+                ("name".to_string(), OGRFieldType::OFTString, 32),
+                ("visited".to_string(), OGRFieldType::OFTInteger, 0),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_field_subtype() -> Result<()> {
+        let field = FieldDefn::new("flag", OGRFieldType::OFTInteger)?;
+        assert_eq!(field.subtype(), FieldSubType::None);
+        field.set_subtype(FieldSubType::Boolean);
+        assert_eq!(field.subtype(), FieldSubType::Boolean);
+
+        let mut ds = memory_dataset()?;
+        let mut layer = memory_layer(&mut ds, "", OGRwkbGeometryType::wkbUnknown, &[])?;
+        layer.create_field(&field)?;
+        layer.create_field(
+            FieldDefn::new("ratio", OGRFieldType::OFTReal)?.with_subtype(FieldSubType::Float32),
+        )?;
+
+        let subtypes = layer
+            .defn()
+            .fields()
+            .map(|f| f.subtype())
+            .collect::<Vec<_>>();
+        assert_eq!(subtypes, vec![FieldSubType::Boolean, FieldSubType::Float32]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_geojson_boolean_subtype() -> Result<()> {
+        let fixture = InMemoryFixture::new("test_geojson_boolean_subtype.geojson");
+        let geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [{
+                "type": "Feature",
+                "properties": { "name": "a", "visited": true },
+                "geometry": { "type": "Point", "coordinates": [1, 2] }
+            }]
+        }"#;
+        crate::vsi::create_mem_file(fixture.path(), geojson.as_bytes().to_vec())?;
+
+        let ds = Dataset::open(fixture.path())?;
+        let layer = ds.layer(0)?;
+        let defn = layer.defn();
+        let visited = defn.fields().find(|f| f.name() == "visited").unwrap();
+        assert_eq!(visited.field_type(), OGRFieldType::OFTInteger);
+        assert_eq!(visited.subtype(), FieldSubType::Boolean);
+        let name = defn.fields().find(|f| f.name() == "name").unwrap();
+        assert_eq!(name.subtype(), FieldSubType::None);
+        Ok(())
+    }
+
     #[test]
     fn test_schema() {
         let ds = Dataset::open(fixture("roads.geojson")).unwrap();
@@ -1565,6 +2430,32 @@ mod tests {
         assert_eq!(layer.features().count(), 7);
     }
 
+    #[test]
+    fn test_spatial_filter_rect_points() {
+        let mut ds = memory_dataset().unwrap();
+        let mut layer = memory_layer(&mut ds, "grid", OGRwkbGeometryType::wkbPoint, &[]).unwrap();
+        for x in 0..5 {
+            for y in 0..5 {
+                let point = Geometry::from_wkt(&format!("POINT ({x} {y})")).unwrap();
+                layer.create_feature(point).unwrap();
+            }
+        }
+
+        layer.set_spatial_filter_rect(0.5, 0.5, 2.5, 2.5);
+        let mut inside = layer
+            .features()
+            .map(|f| f.geometry().unwrap().wkt().unwrap())
+            .collect::<Vec<_>>();
+        inside.sort();
+        assert_eq!(
+            inside,
+            vec!["POINT (1 1)", "POINT (1 2)", "POINT (2 1)", "POINT (2 2)"]
+        );
+
+        layer.clear_spatial_filter();
+        assert_eq!(layer.features().count(), 25);
+    }
+
     #[test]
     fn test_database_lock_issue() {
         use gdal_sys::OGRwkbGeometryType;
@@ -73,7 +73,7 @@ mod options;
 pub mod sql;
 mod transaction;
 
-pub use defn::{Defn, Field, FieldIterator};
+pub use defn::{Defn, Field, FieldIterator, FieldSubType};
 pub use feature::{
     field_type_to_name, Feature, FeatureIterator, FieldValue, FieldValueIterator,
     OwnedFeatureIterator,
@@ -15,9 +15,11 @@ use crate::vector::Geometry;
 /// These include:
 /// * ["Well Known" representations of geometry][wikipedia].
 /// * [GeoJSON][geojson]
+/// * [GML][gml]
 ///
 /// [wikipedia]: https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry
 /// [geojson]: https://geojson.org/
+/// [gml]: https://www.ogc.org/standard/gml/
 ///
 impl Geometry {
     /// Create a geometry by parsing a
@@ -73,8 +75,8 @@ impl Geometry {
 
     /// Create a geometry by parsing a
     /// [GML](https://en.wikipedia.org/wiki/Geography_Markup_Language) string.
-    pub fn from_gml(json: &str) -> Result<Geometry> {
-        let c_gml = CString::new(json)?;
+    pub fn from_gml(gml: &str) -> Result<Geometry> {
+        let c_gml = CString::new(gml)?;
         let c_geom = unsafe { gdal_sys::OGR_G_CreateFromGML(c_gml.as_ptr()) };
         if c_geom.is_null() {
             return Err(_last_null_pointer_err("OGR_G_CreateFromGML"));
@@ -83,6 +85,13 @@ impl Geometry {
     }
 
     /// Serialize the geometry as WKT.
+    ///
+    /// This is OGR's legacy WKT flavour: Z coordinates are written, but without the `Z`
+    /// tag (e.g. `POINT (1 2 3)`), so readers can't tell the dimension from the geometry
+    /// type, and M values are dropped. Use [`Geometry::iso_wkt`] to preserve both.
+    ///
+    /// See: [`OGR_G_ExportToWkt`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_ExportToWkt12OGRGeometryHPPc)
+    #[doc(alias = "to_wkt")]
     pub fn wkt(&self) -> Result<String> {
         let mut c_wkt = null_mut();
         let rv = unsafe { gdal_sys::OGR_G_ExportToWkt(self.c_geometry(), &mut c_wkt) };
@@ -98,6 +107,14 @@ impl Geometry {
     }
 
     /// Serialize the geometry as SFSQL 1.2 / ISO SQL / MM Part 3 WKT.
+    ///
+    /// Unlike [`Geometry::wkt`], the geometry type carries the `Z`, `M` or `ZM` tag,
+    /// e.g. `POINT Z (1 2 3)`, and M values are kept.
+    ///
+    /// Curve types such as `CIRCULARSTRING` and `COMPOUNDCURVE` are also written as such.
+    ///
+    /// See: [`OGR_G_ExportToIsoWkt`](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_G_ExportToIsoWkt12OGRGeometryHPPc)
+    #[doc(alias = "wkt_iso")]
     pub fn iso_wkt(&self) -> Result<String> {
         let mut c_wkt = null_mut();
         let rv = unsafe { gdal_sys::OGR_G_ExportToIsoWkt(self.c_geometry(), &mut c_wkt) };
@@ -137,6 +154,11 @@ impl Geometry {
     /// Serializes the geometry to SFSQL 1.2 / ISO SQL / MM Part 3
     /// [WKB](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry#Well-known_binary)
     /// (Well-Known Binary) format.
+    ///
+    /// Like [`Geometry::iso_wkt`], this keeps the Z and M dimensions, and curve types.
+    ///
+    /// See: [`OGR_G_ExportToIsoWkb`](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_G_ExportToIsoWkb12OGRGeometryH15OGRwkbByteOrderPh)
+    #[doc(alias = "wkb_iso")]
     pub fn iso_wkb(&self) -> Result<Vec<u8>> {
         let wkb_size = unsafe { gdal_sys::OGR_G_WkbSize(self.c_geometry()) as usize };
         // We default to little-endian for now. A WKB string explicitly indicates the byte
@@ -166,6 +188,16 @@ impl Geometry {
         unsafe { gdal_sys::VSIFree(c_json as *mut c_void) };
         rv
     }
+
+    /// Serialize the geometry as a GML 2 fragment, such as `<gml:Point>...</gml:Point>`.
+    ///
+    /// See: [`OGR_G_ExportToGML`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_ExportToGML12OGRGeometryH)
+    pub fn gml(&self) -> Result<String> {
+        let c_gml = unsafe { gdal_sys::OGR_G_ExportToGML(self.c_geometry()) };
+        let rv = _string(c_gml).ok_or_else(|| _last_null_pointer_err("OGR_G_ExportToGML"));
+        unsafe { gdal_sys::VSIFree(c_gml as *mut c_void) };
+        rv
+    }
 }
 
 #[cfg(test)]
@@ -215,4 +247,62 @@ mod tests {
         let res = Geometry::from_gml(json);
         assert!(res.is_err());
     }
+
+    #[test]
+    pub fn test_wkt_dimensions() {
+        let point = Geometry::from_wkt("POINT Z (1 2 3)").unwrap();
+        assert_eq!(point.wkt().unwrap(), "POINT (1 2 3)");
+        let iso = point.iso_wkt().unwrap();
+        assert_eq!(iso, "POINT Z (1 2 3)");
+        let round_trip = Geometry::from_wkt(&iso).unwrap();
+        assert_eq!(round_trip.get_point(0), (1.0, 2.0, 3.0));
+        assert_eq!(round_trip, point);
+
+        // legacy WKT has no room for M values
+        let point = Geometry::from_wkt("POINT ZM (1 2 3 4)").unwrap();
+        assert_eq!(point.wkt().unwrap(), "POINT (1 2 3)");
+        assert_eq!(point.iso_wkt().unwrap(), "POINT ZM (1 2 3 4)");
+    }
+
+    #[test]
+    pub fn test_iso_wkt_measured_and_curves() {
+        let line = Geometry::from_wkt("LINESTRING ZM (0 0 1 10,1 1 2 20)").unwrap();
+        assert_eq!(line.iso_wkt().unwrap(), "LINESTRING ZM (0 0 1 10,1 1 2 20)");
+        let from_wkb = Geometry::from_wkb(&line.iso_wkb().unwrap()).unwrap();
+        assert_eq!(from_wkb.get_point_zm(1), (1.0, 1.0, 2.0, 20.0));
+
+        for wkt in [
+            "CIRCULARSTRING Z (0 0 1,1 1 1,2 0 1)",
+            "COMPOUNDCURVE ((0 0,1 0),CIRCULARSTRING (1 0,2 1,3 0))",
+        ] {
+            let curve = Geometry::from_wkt(wkt).unwrap();
+            assert_eq!(curve.iso_wkt().unwrap(), wkt);
+            let from_wkb = Geometry::from_wkb(&curve.iso_wkb().unwrap()).unwrap();
+            assert_eq!(from_wkb.geometry_type(), curve.geometry_type());
+            assert_eq!(from_wkb.iso_wkt().unwrap(), wkt);
+        }
+    }
+
+    #[test]
+    pub fn test_polygon_round_trips() {
+        let wkt = "POLYGON ((0 0,0 10,10 10,10 0,0 0),(2 2,4 2,4 4,2 4,2 2))";
+        let orig_geom = Geometry::from_wkt(wkt).unwrap();
+        assert_eq!(orig_geom.wkt().unwrap(), wkt);
+
+        let from_wkt = Geometry::from_wkt(&orig_geom.wkt().unwrap()).unwrap();
+        assert_eq!(from_wkt, orig_geom);
+
+        let from_wkb = Geometry::from_wkb(&orig_geom.wkb().unwrap()).unwrap();
+        assert_eq!(from_wkb, orig_geom);
+
+        let json = orig_geom.json().unwrap();
+        assert!(json.contains(r#""type": "Polygon""#));
+        let from_json = Geometry::from_geojson(&json).unwrap();
+        assert_eq!(from_json, orig_geom);
+
+        let gml = orig_geom.gml().unwrap();
+        assert!(gml.starts_with("<gml:Polygon>"));
+        let from_gml = Geometry::from_gml(&gml).unwrap();
+        assert_eq!(from_gml, orig_geom);
+    }
 }
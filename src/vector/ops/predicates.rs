@@ -131,6 +131,22 @@ impl Geometry {
         let p = unsafe { gdal_sys::OGR_G_Overlaps(self.c_geometry(), other.c_geometry()) };
         p != 0
     }
+
+    /// Tests if two geometries are equivalent;
+    /// they have the same type and the same vertices, in the same order.
+    ///
+    /// This is the comparison used by `==`. It does not require GEOS, and it is stricter
+    /// than [_spatial equality_][DE-9IM]: rings starting at a different vertex compare
+    /// unequal. Use `a.within(&b) && b.within(&a)` to test for spatial equality instead.
+    ///
+    /// See: [`OGR_G_Equals`][OGR_G_Equals]
+    ///
+    /// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM#Spatial_predicates
+    /// [OGR_G_Equals]: https://gdal.org/api/vector_c_api.html#_CPPv412OGR_G_Equals12OGRGeometryH12OGRGeometryH
+    pub fn equals(&self, other: &Self) -> bool {
+        let p = unsafe { gdal_sys::OGR_G_Equals(self.c_geometry(), other.c_geometry()) };
+        p != 0
+    }
 }
 
 #[cfg(test)]
@@ -188,4 +204,29 @@ mod test {
         let poly2 = Geometry::from_wkt("POLYGON((0 -5,10 5,10 -5,0 -5))").unwrap();
         assert!(poly1.overlaps(&poly2));
     }
+
+    #[test]
+    fn test_disjoint_polygons() {
+        let poly1 = Geometry::from_wkt("POLYGON((0 0, 0 1, 1 1, 1 0, 0 0))").unwrap();
+        let poly2 = Geometry::from_wkt("POLYGON((5 5, 5 6, 6 6, 6 5, 5 5))").unwrap();
+        assert!(poly1.disjoint(&poly2));
+        assert!(!poly1.intersects(&poly2));
+        assert!(!poly1.touches(&poly2));
+        assert!(!poly1.overlaps(&poly2));
+        assert!(!poly1.contains(&poly2));
+        assert!(!poly1.within(&poly2));
+    }
+
+    #[test]
+    fn test_equals() {
+        let poly1 = Geometry::from_wkt("POLYGON((0 0, 0 1, 1 1, 1 0, 0 0))").unwrap();
+        let poly2 = Geometry::from_wkt("POLYGON((0 0, 0 1, 1 1, 1 0, 0 0))").unwrap();
+        assert!(poly1.equals(&poly2));
+        assert_eq!(poly1, poly2);
+
+        // same region, but the ring starts at another vertex
+        let poly3 = Geometry::from_wkt("POLYGON((1 1, 1 0, 0 0, 0 1, 1 1))").unwrap();
+        assert!(!poly1.equals(&poly3));
+        assert!(poly1.within(&poly3) && poly3.within(&poly1));
+    }
 }
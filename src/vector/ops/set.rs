@@ -109,6 +109,103 @@ impl Geometry {
             Some(Geometry::with_c_geometry(ogr_geom, true))
         }
     }
+
+    /// Computes the [symmetric difference][sym_difference] of `self` and `other`.
+    ///
+    /// Generates a new geometry covering the regions that are in either geometry, but not in both.
+    ///
+    /// # Notes
+    /// * Geometry validity is not checked, and invalid geometry will generate unpredictable results.
+    ///   Use [`Geometry::is_valid`] if validity might be in question.
+    /// * If GEOS is *not* enabled, this function will always return `None`.
+    ///   You may check for GEOS support with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// # Returns
+    /// * `Some(geometry)`: a new `Geometry` representing the computed symmetric difference
+    /// * `None`: when the symmetric difference could not be computed
+    ///
+    /// See: [`OGR_G_SymDifference`][OGR_G_SymDifference]
+    ///
+    /// [OGR_G_SymDifference]: https://gdal.org/api/vector_c_api.html#_CPPv419OGR_G_SymDifference12OGRGeometryH12OGRGeometryH
+    /// [sym_difference]: https://en.wikipedia.org/wiki/Symmetric_difference
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn sym_difference(&self, other: &Self) -> Option<Self> {
+        if !self.has_gdal_ptr() {
+            return None;
+        }
+        if !other.has_gdal_ptr() {
+            return None;
+        }
+        unsafe {
+            let ogr_geom = gdal_sys::OGR_G_SymDifference(self.c_geometry(), other.c_geometry());
+            if ogr_geom.is_null() {
+                return None;
+            }
+            Some(Geometry::with_c_geometry(ogr_geom, true))
+        }
+    }
+
+    /// Computes the union of all the members of this multi-polygon.
+    ///
+    /// This is usually faster than merging the members one by one with [`Geometry::union`].
+    ///
+    /// # Notes
+    /// * Only multi-polygons are supported. Use [`Geometry::unary_union`] for other
+    ///   collections on GDAL 3.7 or later.
+    /// * If GEOS is *not* enabled, this function will always return `None`.
+    ///   You may check for GEOS support with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// # Returns
+    /// * `Some(geometry)`: a new `Geometry` representing the union of the members
+    /// * `None`: when the union could not be computed
+    ///
+    /// See: [`OGR_G_UnionCascaded`][OGR_G_UnionCascaded]
+    ///
+    /// [OGR_G_UnionCascaded]: https://gdal.org/api/vector_c_api.html#_CPPv419OGR_G_UnionCascaded12OGRGeometryH
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn union_cascaded(&self) -> Option<Self> {
+        if !self.has_gdal_ptr() {
+            return None;
+        }
+        unsafe {
+            let ogr_geom = gdal_sys::OGR_G_UnionCascaded(self.c_geometry());
+            if ogr_geom.is_null() {
+                return None;
+            }
+            Some(Geometry::with_c_geometry(ogr_geom, true))
+        }
+    }
+
+    /// Computes the union of all the components of this geometry.
+    ///
+    /// Unlike [`Geometry::union_cascaded`], this works on any geometry, including
+    /// collections of mixed types, and also dissolves overlaps within a single polygon.
+    ///
+    /// # Notes
+    /// * If GEOS is *not* enabled, this function will always return `None`.
+    ///   You may check for GEOS support with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// # Returns
+    /// * `Some(geometry)`: a new `Geometry` representing the union of the components
+    /// * `None`: when the union could not be computed
+    ///
+    /// See: [`OGR_G_UnaryUnion`][OGR_G_UnaryUnion]
+    ///
+    /// [OGR_G_UnaryUnion]: https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_UnaryUnion12OGRGeometryH
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    #[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
+    pub fn unary_union(&self) -> Option<Self> {
+        if !self.has_gdal_ptr() {
+            return None;
+        }
+        unsafe {
+            let ogr_geom = gdal_sys::OGR_G_UnaryUnion(self.c_geometry());
+            if ogr_geom.is_null() {
+                return None;
+            }
+            Some(Geometry::with_c_geometry(ogr_geom, true))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -244,4 +341,60 @@ mod tests {
         let res = geom.difference(&other);
         assert_eq!(res.unwrap().area(), 25.0);
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_sym_difference_success() {
+        let geom =
+            Geometry::from_wkt("POLYGON ((0.0 10.0, 0.0 0.0, 10.0 0.0, 10.0 10.0, 0.0 10.0))")
+                .unwrap();
+        let other = Geometry::from_wkt("POLYGON ((1 -5, 1 1, -5 1, -5 -5, 1 -5))").unwrap();
+
+        let res = geom.sym_difference(&other).unwrap();
+        assert_eq!(res.area(), 99.0 + 35.0);
+    }
+
+    #[test]
+    fn test_sym_difference_no_gdal_ptr() {
+        let geom =
+            Geometry::from_wkt("POLYGON ((0.0 10.0, 0.0 0.0, 10.0 0.0, 10.0 10.0, 0.0 10.0))")
+                .unwrap();
+        let other = unsafe { Geometry::lazy_feature_geometry() };
+
+        let res = geom.sym_difference(&other);
+        assert!(res.is_none());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_union_cascaded() {
+        let geom = Geometry::from_wkt(
+            "MULTIPOLYGON (((0 0, 0 10, 10 10, 10 0, 0 0)), ((5 5, 5 15, 15 15, 15 5, 5 5)))",
+        )
+        .unwrap();
+
+        let res = geom.union_cascaded().unwrap();
+        assert_eq!(
+            res.geometry_type(),
+            ::gdal_sys::OGRwkbGeometryType::wkbPolygon
+        );
+        assert_eq!(res.area(), 175.0);
+    }
+
+    #[test]
+    #[cfg(any(all(major_ge_3, minor_ge_7), major_ge_4))]
+    #[allow(clippy::float_cmp)]
+    fn test_unary_union() {
+        let geom = Geometry::from_wkt(
+            "GEOMETRYCOLLECTION (POLYGON ((0 0, 0 10, 10 10, 10 0, 0 0)), POLYGON ((5 5, 5 15, 15 15, 15 5, 5 5)), POINT (2 2))",
+        )
+        .unwrap();
+
+        let res = geom.unary_union().unwrap();
+        assert_eq!(
+            res.geometry_type(),
+            ::gdal_sys::OGRwkbGeometryType::wkbPolygon
+        );
+        assert_eq!(res.area(), 175.0);
+    }
 }
@@ -13,6 +13,10 @@ use crate::vector::Geometry;
 impl Geometry {
     /// Apply arbitrary coordinate transformation to geometry, mutating the [`Geometry`] in-place.
     ///
+    /// If any point fails to transform, an error is returned and the geometry may be left
+    /// partially transformed. Setting the `OGR_ENABLE_PARTIAL_REPROJECTION` configuration option
+    /// to `YES` makes GDAL drop the failing points of line strings instead of erroring out.
+    ///
     /// See: [`OGR_G_Transform`](https://gdal.org/api/vector_c_api.html#_CPPv415OGR_G_Transform12OGRGeometryH28OGRCoordinateTransformationH)
     pub fn transform_inplace(&mut self, htransform: &CoordTransform) -> Result<()> {
         let rv = unsafe { gdal_sys::OGR_G_Transform(self.c_geometry(), htransform.to_c_hct()) };
@@ -27,21 +31,22 @@ impl Geometry {
 
     /// Apply arbitrary coordinate transformation to geometry on a clone of `Self`.
     ///
+    /// `self` is left untouched, even if the transformation fails.
+    /// See [`transform_inplace`][Self::transform_inplace] for the handling of failing points.
+    ///
     /// See: [`OGR_G_Transform`](https://gdal.org/api/vector_c_api.html#_CPPv415OGR_G_Transform12OGRGeometryH28OGRCoordinateTransformationH)
     pub fn transform(&self, htransform: &CoordTransform) -> Result<Geometry> {
-        let new_c_geom = unsafe { gdal_sys::OGR_G_Clone(self.c_geometry()) };
-        let rv = unsafe { gdal_sys::OGR_G_Transform(new_c_geom, htransform.to_c_hct()) };
-        if rv != OGRErr::OGRERR_NONE {
-            return Err(GdalError::OgrError {
-                err: rv,
-                method_name: "OGR_G_Transform",
-            });
-        }
-        Ok(unsafe { Geometry::with_c_geometry(new_c_geom, true) })
+        let mut geom = self.clone();
+        geom.transform_inplace(htransform)?;
+        Ok(geom)
     }
 
     /// Transforms this geometry's coordinates into another [`SpatialRef`], mutating the [`Geometry`] in-place.
     ///
+    /// The geometry must have a spatial reference assigned, see [`Geometry::set_spatial_ref`].
+    /// As with [`transform_inplace`][Self::transform_inplace], the geometry may be left
+    /// partially transformed if some of its points can't be reprojected.
+    ///
     /// See: [`OGR_G_TransformTo`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_TransformTo12OGRGeometryH20OGRSpatialReferenceH)
     pub fn transform_to_inplace(&mut self, spatial_ref: &SpatialRef) -> Result<()> {
         let rv = unsafe { gdal_sys::OGR_G_TransformTo(self.c_geometry(), spatial_ref.to_c_hsrs()) };
@@ -56,22 +61,25 @@ impl Geometry {
 
     /// Transforms this geometry's coordinates into another [`SpatialRef`].
     ///
+    /// `self` is left untouched, even if the transformation fails.
+    ///
     /// See: [`OGR_G_TransformTo`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_TransformTo12OGRGeometryH20OGRSpatialReferenceH)
     pub fn transform_to(&self, spatial_ref: &SpatialRef) -> Result<Geometry> {
-        let new_c_geom = unsafe { gdal_sys::OGR_G_Clone(self.c_geometry()) };
-        let rv = unsafe { gdal_sys::OGR_G_TransformTo(new_c_geom, spatial_ref.to_c_hsrs()) };
-        if rv != OGRErr::OGRERR_NONE {
-            return Err(GdalError::OgrError {
-                err: rv,
-                method_name: "OGR_G_TransformTo",
-            });
-        }
-        Ok(unsafe { Geometry::with_c_geometry(new_c_geom, true) })
+        let mut geom = self.clone();
+        geom.transform_to_inplace(spatial_ref)?;
+        Ok(geom)
     }
 
     /// Compute the convex hull of this geometry.
     ///
+    /// # Notes
+    /// This function requires the GEOS library. Without it, GDAL reports that GEOS
+    /// support is not enabled and an error is returned, see
+    /// [`VersionInfo::has_geos`][has_geos].
+    ///
     /// See: [`OGR_G_ConvexHull`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_ConvexHull12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
     pub fn convex_hull(&self) -> Result<Geometry> {
         let c_geom = unsafe { gdal_sys::OGR_G_ConvexHull(self.c_geometry()) };
         if c_geom.is_null() {
@@ -110,6 +118,9 @@ impl Geometry {
     /// # Arguments
     /// * `tolerance`: the distance tolerance for the simplification.
     ///
+    /// # Notes
+    /// Like [`convex_hull`][Self::convex_hull], this requires GEOS and fails otherwise.
+    ///
     /// See: [`OGR_G_Simplify`](https://gdal.org/api/vector_c_api.html#_CPPv414OGR_G_Simplify12OGRGeometryHd)
     pub fn simplify(&self, tolerance: f64) -> Result<Self> {
         let c_geom = unsafe { gdal_sys::OGR_G_Simplify(self.c_geometry(), tolerance) };
@@ -125,6 +136,9 @@ impl Geometry {
     /// # Arguments
     /// * `tolerance`: the distance tolerance for the simplification.
     ///
+    /// # Notes
+    /// Requires GEOS, see [`convex_hull`][Self::convex_hull].
+    ///
     /// See: [`OGR_G_SimplifyPreserveTopology`](https://gdal.org/api/vector_c_api.html#_CPPv430OGR_G_SimplifyPreserveTopology12OGRGeometryHd)
     pub fn simplify_preserve_topology(&self, tolerance: f64) -> Result<Self> {
         let c_geom =
@@ -136,6 +150,18 @@ impl Geometry {
         Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
     }
 
+    /// Add intermediate vertices in place, so that no segment is longer than `max_length`.
+    ///
+    /// `max_length` is expressed in the units of the geometry's coordinates, i.e. those of
+    /// its spatial reference: degrees for geographic coordinates. Densifying long edges
+    /// before reprojecting them, e.g. for a wide bounding box, keeps them from turning into
+    /// straight lines in the target projection.
+    ///
+    /// See: [`OGR_G_Segmentize`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_Segmentize12OGRGeometryHd)
+    pub fn segmentize(&mut self, max_length: f64) {
+        unsafe { gdal_sys::OGR_G_Segmentize(self.c_geometry(), max_length) };
+    }
+
     /// Compute buffer of geometry
     ///
     /// # Arguments
@@ -144,6 +170,10 @@ impl Geometry {
     /// * `n_quad_segs` specifies the number of segments used to approximate a
     ///   90 degree (quadrant) of curvature.
     ///
+    /// # Notes
+    /// Buffering is computed by GEOS; without it an error is returned, see
+    /// [`convex_hull`][Self::convex_hull].
+    ///
     /// See: [`OGR_G_Buffer`](https://gdal.org/api/vector_c_api.html#_CPPv412OGR_G_Buffer12OGRGeometryHdi)
     pub fn buffer(&self, distance: f64, n_quad_segs: u32) -> Result<Self> {
         let c_geom =
@@ -166,11 +196,13 @@ impl Geometry {
     ///   valid polygons from that "linework".
     /// * `METHOD=STRUCTURE`: First makes all rings valid, then merges shells and subtracts holes
     ///   from shells to generate valid result. Assumes holes and shells are correctly categorized.
+    ///   Requires GEOS >= 3.10.
     /// * `KEEP_COLLAPSED=YES/NO`. Only for `METHOD=STRUCTURE`.
     ///   - `NO` (default):  Collapses are converted to empty geometries
     ///   - `YES`: collapses are converted to a valid geometry of lower dimension
     ///
-    /// When GEOS < 3.8, this method will return `Ok(self.clone())` if it is valid, or `Err` if not.
+    /// When GEOS < 3.8, or GDAL is built without GEOS, this method will return `Ok(self.clone())`
+    /// if it is valid, or `Err` if not.
     ///
     /// See: [OGR_G_MakeValidEx](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_MakeValidEx12OGRGeometryH12CSLConstList)
     ///
@@ -189,7 +221,7 @@ impl Geometry {
         let c_geom = unsafe { gdal_sys::OGR_G_MakeValidEx(self.c_geometry(), opts.as_ptr()) };
 
         if c_geom.is_null() {
-            Err(_last_null_pointer_err("OGR_G_MakeValid"))
+            Err(_last_null_pointer_err("OGR_G_MakeValidEx"))
         } else {
             Ok(unsafe { Geometry::with_c_geometry(c_geom, true) })
         }
@@ -199,8 +231,75 @@ impl Geometry {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_almost_eq;
+    use crate::spatial_ref::AxisMappingStrategy;
     use crate::test_utils::SuppressGDALErrorLog;
 
+    #[test]
+    fn test_transform_point_to_web_mercator() -> Result<()> {
+        let mut wgs84 = SpatialRef::from_epsg(4326)?;
+        wgs84.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        let mut web_mercator = SpatialRef::from_epsg(3857)?;
+        web_mercator.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        let ct = CoordTransform::new(&wgs84, &web_mercator)?;
+
+        let point = Geometry::from_wkt("POINT (10 20)")?;
+        let transformed = point.transform(&ct)?;
+        let (x, y, _) = transformed.get_point(0);
+        assert_almost_eq(x, 1113194.9079327357);
+        assert_almost_eq(y, 2273030.926987689);
+        // the source geometry is left untouched
+        assert_eq!(point.get_point(0), (10.0, 20.0, 0.0));
+
+        let mut point = point;
+        point.set_spatial_ref(wgs84);
+        let transformed = point.transform_to(&web_mercator)?;
+        let (x, y, _) = transformed.get_point(0);
+        assert_almost_eq(x, 1113194.9079327357);
+        assert_almost_eq(y, 2273030.926987689);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transform_failure() -> Result<()> {
+        let _nolog = SuppressGDALErrorLog::new();
+        let mut wgs84 = SpatialRef::from_epsg(4326)?;
+        wgs84.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        let mut web_mercator = SpatialRef::from_epsg(3857)?;
+        web_mercator.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        let ct = CoordTransform::new(&wgs84, &web_mercator)?;
+
+        let point = Geometry::from_wkt("POINT (1000000 1000000)")?;
+        assert!(point.transform(&ct).is_err());
+        assert_eq!(point.get_point(0), (1000000.0, 1000000.0, 0.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_transform_to_assigns_spatial_ref() -> Result<()> {
+        let _nolog = SuppressGDALErrorLog::new();
+        let mut wgs84 = SpatialRef::from_epsg(4326)?;
+        wgs84.set_axis_mapping_strategy(AxisMappingStrategy::TraditionalGisOrder);
+        let web_mercator = SpatialRef::from_epsg(3857)?;
+
+        // without a source spatial reference there is nothing to transform from
+        let mut line = Geometry::from_wkt("LINESTRING (10 20,1000000 1000000)")?;
+        assert!(line.transform_to(&web_mercator).is_err());
+
+        // a single failing point fails the whole geometry
+        line.set_spatial_ref(wgs84.clone());
+        assert!(line.transform_to(&web_mercator).is_err());
+
+        let mut point = Geometry::from_wkt("POINT (10 20)")?;
+        point.set_spatial_ref(wgs84);
+        point.transform_to_inplace(&web_mercator)?;
+        assert_eq!(point.spatial_ref().unwrap().auth_code()?, 3857);
+        let (x, y, _) = point.get_point(0);
+        assert_almost_eq(x, 1113194.9079327357);
+        assert_almost_eq(y, 2273030.926987689);
+        Ok(())
+    }
+
     #[test]
     fn test_convex_hull() {
         let star = "POLYGON ((0 1,3 1,1 3,1.5 0.0,2 3,0 1))";
@@ -234,6 +333,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_simplify_dense_line() -> Result<()> {
+        // 101 vertices zig-zagging by at most 0.01 around y = 0
+        let mut line = Geometry::empty(gdal_sys::OGRwkbGeometryType::wkbLineString)?;
+        for i in 0..=100 {
+            let y = if i % 2 == 0 { 0.0 } else { 0.01 };
+            line.add_point_2d((i as f64, y));
+        }
+        let simplified = line.simplify(0.1)?;
+        assert_eq!(simplified.point_count(), 2);
+        assert_eq!(simplified.get_point(0), (0.0, 0.0, 0.0));
+        assert_eq!(simplified.get_point(1), (100.0, 0.0, 0.0));
+
+        let preserved = line.simplify_preserve_topology(0.1)?;
+        assert!(preserved.point_count() < line.point_count());
+        Ok(())
+    }
+
     #[test]
     fn test_simplify_preserve_topology() -> Result<()> {
         let test = Geometry::from_wkt("LINESTRING(0 0,1 0,10 0)")?;
@@ -242,6 +359,21 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_segmentize() -> Result<()> {
+        let mut line = Geometry::from_wkt("LINESTRING (0 0, 10 0)")?;
+        line.segmentize(1.0);
+        assert_eq!(line.point_count(), 11);
+        assert_eq!(line.get_point(0), (0.0, 0.0, 0.0));
+        assert_eq!(line.get_point(10), (10.0, 0.0, 0.0));
+        assert!((line.length() - 10.0).abs() < 1e-12);
+
+        // segments that are already short enough are kept as is
+        line.segmentize(5.0);
+        assert_eq!(line.point_count(), 11);
+        Ok(())
+    }
+
     #[test]
     pub fn test_buffer() {
         let geom = Geometry::from_wkt("POINT(0 0)").unwrap();
@@ -251,6 +383,10 @@ mod tests {
             ::gdal_sys::OGRwkbGeometryType::wkbPolygon
         );
         assert!(buffered.area() > 10.0);
+        // two segments per quadrant make an octagon, closed by repeating the first point
+        let ring = buffered.get_geometry(0);
+        assert_eq!(ring.point_count(), 9);
+        assert!((buffered.area() - 200.0 * 2f64.sqrt()).abs() < 1e-6);
     }
 
     #[test]
@@ -288,9 +424,37 @@ mod tests {
         let src =
             Geometry::from_wkt("POLYGON ((0 0, 0 10, 10 10, 10 0, 0 0),(5 5, 15 10, 15 0, 5 5))")
                 .unwrap();
-        let opts = CslStringList::from_iter(["STRUCTURE=LINEWORK"]);
+        let opts = CslStringList::from_iter(["METHOD=LINEWORK"]);
         let dst = src.make_valid(&opts);
         assert!(dst.is_ok(), "{dst:?}");
-        assert!(dst.unwrap().is_valid());
+        let dst = dst.unwrap();
+        assert!(dst.is_valid());
+        // the part of the hole outside of the shell becomes a polygon of its own
+        assert_almost_eq(dst.area(), 125.0);
+    }
+
+    #[test]
+    /// Repairable case, keeping the shell and hole structure
+    pub fn test_make_valid_structure() {
+        let (mut major, mut minor, mut patch) = (0, 0, 0);
+        let has_geos = unsafe { gdal_sys::OGRGetGEOSVersion(&mut major, &mut minor, &mut patch) };
+        if !has_geos || (major, minor) < (3, 10) {
+            // METHOD=STRUCTURE is not supported
+            return;
+        }
+
+        let bowtie = Geometry::from_wkt("POLYGON ((0 0, 10 10, 0 10, 10 0, 0 0))").unwrap();
+        let opts = CslStringList::from_iter(["METHOD=STRUCTURE"]);
+        let dst = bowtie.make_valid(&opts).unwrap();
+        assert!(dst.is_valid());
+        assert_almost_eq(dst.area(), 50.0);
+
+        let src =
+            Geometry::from_wkt("POLYGON ((0 0, 0 10, 10 10, 10 0, 0 0),(5 5, 15 10, 15 0, 5 5))")
+                .unwrap();
+        let dst = src.make_valid(&opts).unwrap();
+        assert!(dst.is_valid());
+        // only the part of the hole inside of the shell is subtracted
+        assert_almost_eq(dst.area(), 87.5);
     }
 }
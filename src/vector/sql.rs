@@ -143,6 +143,9 @@ impl Dataset {
         let cpl_err = unsafe { gdal_sys::CPLGetLastErrorType() };
 
         if cpl_err != CPLErr::CE_None {
+            if !c_layer.is_null() {
+                unsafe { gdal_sys::GDALDatasetReleaseResultSet(c_dataset, c_layer) };
+            }
             return Err(_last_cpl_err(cpl_err));
         }
 
@@ -163,7 +166,7 @@ impl Dataset {
 mod tests {
     use std::collections::HashSet;
 
-    use crate::test_utils::SuppressGDALErrorLog;
+    use crate::test_utils::{open_gpkg_for_update, SuppressGDALErrorLog};
     use crate::{
         test_utils::fixture,
         vector::{sql, Geometry, LayerAccess},
@@ -283,6 +286,47 @@ mod tests {
         assert!(result_set.is_none());
     }
 
+    #[test]
+    #[cfg_attr(feature = "gdal-src", ignore)]
+    fn test_sql_count_dialects() {
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        let query = "SELECT COUNT(*) AS n FROM roads WHERE highway = 'pedestrian'";
+
+        for dialect in [sql::Dialect::OGR, sql::Dialect::SQLITE] {
+            let mut result_set = ds.execute_sql(query, None, dialect).unwrap().unwrap();
+            assert_eq!(result_set.feature_count(), 1);
+            let n_idx = result_set.defn().field_index("n").unwrap();
+            let feature = result_set.features().next().unwrap();
+            assert_eq!(feature.field_as_integer64(n_idx).unwrap(), Some(10));
+        }
+    }
+
+    #[test]
+    fn test_sql_count_gpkg() {
+        let (_temp_path, ds) = open_gpkg_for_update(&fixture("poly.gpkg"));
+        let layer = ds.layer(0).unwrap();
+        let expected = layer.feature_count();
+        let query = format!("SELECT COUNT(*) AS n FROM \"{}\"", layer.name());
+
+        let mut result_set = ds
+            .execute_sql(&query, None, sql::Dialect::DEFAULT)
+            .unwrap()
+            .unwrap();
+        let n_idx = result_set.defn().field_index("n").unwrap();
+        let feature = result_set.features().next().unwrap();
+        let count = feature.field_as_integer64(n_idx).unwrap().unwrap();
+        assert_eq!(count as u64, expected);
+
+        // native GeoPackage statements without a result set
+        let column = layer.defn().fields().next().unwrap().name();
+        let query = format!(
+            "CREATE INDEX idx_sql_count ON \"{}\" (\"{column}\")",
+            layer.name()
+        );
+        let result_set = ds.execute_sql(&query, None, sql::Dialect::DEFAULT).unwrap();
+        assert!(result_set.is_none());
+    }
+
     #[test]
     fn test_sql_bad_query() {
         let _nolog = SuppressGDALErrorLog::new();
@@ -1,6 +1,7 @@
 use crate::errors::{GdalError, Result};
 use crate::Dataset;
 use gdal_sys::OGRErr;
+use std::ffi::c_int;
 use std::ops::{Deref, DerefMut};
 
 /// Represents an in-flight transaction on a dataset.
@@ -167,8 +168,18 @@ impl Dataset {
     /// # }
     /// ```
     pub fn start_transaction(&mut self) -> Result<Transaction<'_>> {
-        let force = 1;
-        let rv = unsafe { gdal_sys::GDALDatasetStartTransaction(self.c_dataset(), force) };
+        self.start_transaction_ex(true)
+    }
+
+    /// Like [`Dataset::start_transaction`], but lets the caller decide whether emulated
+    /// transactions are acceptable.
+    ///
+    /// With `force` set to `false`, only drivers with native transaction support, such as GPKG,
+    /// SQLite and PostgreSQL, will start one. Otherwise the slower emulation mentioned in the
+    /// limitations of [`Dataset::start_transaction`] may be used. Either way, datasources without
+    /// any support return [`OGRErr::OGRERR_UNSUPPORTED_OPERATION`].
+    pub fn start_transaction_ex(&mut self, force: bool) -> Result<Transaction<'_>> {
+        let rv = unsafe { gdal_sys::GDALDatasetStartTransaction(self.c_dataset(), force as c_int) };
         if rv != OGRErr::OGRERR_NONE {
             return Err(GdalError::OgrError {
                 err: rv,
@@ -181,9 +192,12 @@ impl Dataset {
 
 #[cfg(test)]
 mod tests {
-    use crate::test_utils::{fixture, open_gpkg_for_update};
-    use crate::vector::{Geometry, LayerAccess};
-    use crate::Dataset;
+    use gdal_sys::{OGRErr, OGRwkbGeometryType};
+
+    use crate::errors::GdalError;
+    use crate::test_utils::{fixture, open_gpkg_for_update, InMemoryFixture};
+    use crate::vector::{Geometry, LayerAccess, LayerOptions};
+    use crate::{Dataset, DriverManager};
 
     fn polygon() -> Geometry {
         Geometry::from_wkt("POLYGON ((30 10, 40 40, 20 40, 10 20, 30 10))").unwrap()
@@ -239,6 +253,40 @@ mod tests {
     #[test]
     fn test_start_transaction_unsupported() {
         let mut ds = Dataset::open(fixture("roads.geojson")).unwrap();
-        assert!(ds.start_transaction().is_err());
+        assert!(matches!(
+            ds.start_transaction(),
+            Err(GdalError::OgrError {
+                err: OGRErr::OGRERR_UNSUPPORTED_OPERATION,
+                method_name: "GDALDatasetStartTransaction",
+            })
+        ));
+        assert!(ds.start_transaction_ex(false).is_err());
+    }
+
+    #[test]
+    fn test_transaction_bulk_insert_gpkg() {
+        let output = InMemoryFixture::new("transaction_bulk.gpkg");
+        {
+            let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+            let mut ds = driver.create_vector_only(output.path()).unwrap();
+            let mut txn = ds.start_transaction_ex(false).unwrap();
+            let mut layer = txn
+                .create_layer(LayerOptions {
+                    name: "points",
+                    ty: OGRwkbGeometryType::wkbPoint,
+                    ..Default::default()
+                })
+                .unwrap();
+            for i in 0..1000 {
+                let wkt = format!("POINT ({} {})", i % 40, i / 40);
+                layer
+                    .create_feature(Geometry::from_wkt(&wkt).unwrap())
+                    .unwrap();
+            }
+            txn.commit().unwrap();
+        }
+
+        let ds = Dataset::open(output.path()).unwrap();
+        assert_eq!(ds.layer_by_name("points").unwrap().feature_count(), 1000);
     }
 }
@@ -3,14 +3,21 @@
 //! This module provides safe access to a subset of the [GDAL VSI Functions](https://gdal.org/doxygen/cpl__vsi_8h.html).
 //! See [GDAL Virtual File Systems document](https://gdal.org/user/virtual_file_systems.html) for details.
 
+use std::ffi::{c_char, c_int, c_void, CString};
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
 use std::marker::PhantomData;
 use std::mem::ManuallyDrop;
 use std::path::{Path, PathBuf};
+use std::ptr;
 
-use gdal_sys::{VSIFCloseL, VSIFileFromMemBuffer, VSIFree, VSIGetMemFileBuffer, VSIUnlink};
+use gdal_sys::{
+    vsi_l_offset, CPLErr, VSIFCloseL, VSIFileFromMemBuffer, VSIFree, VSIGetMemFileBuffer, VSIUnlink,
+};
 
 use crate::errors::{GdalError, Result};
-use crate::utils::{_last_null_pointer_err, _path_to_c_string, _pathbuf_array};
+use crate::utils::{
+    _last_cpl_err, _last_null_pointer_err, _path_to_c_string, _pathbuf_array, _string,
+};
 
 /// Read the file names from a virtual file system with optional recursion.
 pub fn read_dir<P: AsRef<Path>>(path: P, recursive: bool) -> Result<Vec<PathBuf>> {
@@ -192,6 +199,261 @@ where
     Ok(fun(slice))
 }
 
+/// A file opened by a [`VirtualFileSystem`].
+///
+/// This is implemented for every `Read + Write + Seek + Send` type, such as
+/// [`std::fs::File`] or [`std::io::Cursor<Vec<u8>>`](std::io::Cursor). Read-only sources
+/// can return an error from [`Write::write`].
+pub trait VirtualFile: Read + Write + Seek + Send {}
+
+impl<T: Read + Write + Seek + Send> VirtualFile for T {}
+
+/// A file system implemented in Rust, which GDAL can use through a custom prefix
+/// once installed with [`install_filesystem`].
+///
+/// # Thread safety
+///
+/// GDAL may open files from any thread, possibly concurrently, so the file system must be
+/// `Send + Sync`. Each opened [`VirtualFile`] is only used by one thread at a time, but not
+/// necessarily the one that opened it, hence the `Send` bound.
+pub trait VirtualFileSystem: Send + Sync + 'static {
+    /// Opens the file at `path`, which includes the prefix the file system was installed
+    /// under, e.g. `/vsimyfs/data/image.tif`.
+    ///
+    /// `access` is an `fopen`-style mode, e.g. `"rb"` for reading, `"r+b"` for updating or
+    /// `"wb"` for creating a file. Returning an error makes GDAL fail to open the file.
+    fn open(&self, path: &str, access: &str) -> std::io::Result<Box<dyn VirtualFile>>;
+
+    /// Returns the size in bytes of the file at `path`, or an error if it doesn't exist.
+    ///
+    /// GDAL checks that a file exists with `VSIStatL` before opening it in many places, e.g.
+    /// for the SQLite-based GeoPackage driver or when looking for side-car files. The default
+    /// implementation opens the file for reading and seeks to its end; override it if that is
+    /// expensive.
+    fn stat(&self, path: &str) -> std::io::Result<u64> {
+        self.open(path, "rb")?.seek(SeekFrom::End(0))
+    }
+}
+
+/// The platform struct behind `VSIStatBufL`, which is opaque in the bindings.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+type StatBuf = libc::stat64;
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+type StatBuf = libc::stat;
+
+/// State of a file opened through [`install_filesystem`], handed to GDAL as `pFile`.
+struct PluginFile {
+    file: Box<dyn VirtualFile>,
+    eof: bool,
+}
+
+/// Runs the body of a file system callback, returning `on_panic` if it panics, since
+/// unwinding into GDAL is undefined behavior.
+fn _catch_unwind<T>(on_panic: T, f: impl FnOnce() -> T) -> T {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_or(on_panic)
+}
+
+/// Makes `filesystem` available to GDAL under `prefix`, e.g. `/vsimyfs/`.
+///
+/// Any path starting with `prefix`, such as `/vsimyfs/data/image.tif`, is then opened through
+/// [`VirtualFileSystem::open`], so datasets can be read from and written to arbitrary Rust
+/// sources without going through `/vsimem/`.
+///
+/// The prefix must start with `/vsi` and end with `/`, and must not already be in use.
+///
+/// # Lifetime
+///
+/// GDAL keeps using the file system for as long as the process runs, so it is leaked and
+/// never dropped. Installing file systems should be done once, e.g. at startup.
+///
+/// # Limitations
+///
+/// Only opening, reading, writing, seeking within and getting the size of files is supported:
+/// directory listing, renaming and deleting fail for paths under `prefix`. Paths are always
+/// reported as regular files.
+///
+/// Panics in the file system or its files can't unwind through GDAL. They are caught and
+/// reported to GDAL as failed I/O operations instead.
+///
+/// See: [`VSIInstallPluginHandler`](https://gdal.org/api/cpl.html#_CPPv423VSIInstallPluginHandlerPKcPK34VSIFilesystemPluginCallbacksStruct)
+pub fn install_filesystem<F: VirtualFileSystem>(prefix: &str, filesystem: F) -> Result<()> {
+    unsafe extern "C" fn stat<F: VirtualFileSystem>(
+        user_data: *mut c_void,
+        filename: *const c_char,
+        stat_buf: *mut gdal_sys::VSIStatBufL,
+        _flags: c_int,
+    ) -> c_int {
+        _catch_unwind(-1, || {
+            let filesystem = &*(user_data as *const F);
+            let Some(filename) = _string(filename) else {
+                return -1;
+            };
+            match filesystem.stat(&filename) {
+                Ok(size) => {
+                    let stat_buf = stat_buf as *mut StatBuf;
+                    ptr::write_bytes(stat_buf, 0, 1);
+                    (*stat_buf).st_mode = libc::S_IFREG as _;
+                    (*stat_buf).st_size = size as _;
+                    0
+                }
+                Err(_) => -1,
+            }
+        })
+    }
+
+    unsafe extern "C" fn open<F: VirtualFileSystem>(
+        user_data: *mut c_void,
+        filename: *const c_char,
+        access: *const c_char,
+    ) -> *mut c_void {
+        _catch_unwind(ptr::null_mut(), || {
+            let filesystem = &*(user_data as *const F);
+            let (Some(filename), Some(access)) = (_string(filename), _string(access)) else {
+                return ptr::null_mut();
+            };
+            match filesystem.open(&filename, &access) {
+                Ok(file) => Box::into_raw(Box::new(PluginFile { file, eof: false })) as *mut c_void,
+                Err(_) => ptr::null_mut(),
+            }
+        })
+    }
+
+    unsafe extern "C" fn tell(file: *mut c_void) -> vsi_l_offset {
+        _catch_unwind(vsi_l_offset::MAX, || {
+            let file = &mut *(file as *mut PluginFile);
+            file.file.stream_position().unwrap_or(vsi_l_offset::MAX)
+        })
+    }
+
+    unsafe extern "C" fn seek(file: *mut c_void, offset: vsi_l_offset, whence: c_int) -> c_int {
+        _catch_unwind(-1, || {
+            let file = &mut *(file as *mut PluginFile);
+            // relative offsets are passed as two's complement
+            let pos = match whence {
+                0 => SeekFrom::Start(offset),
+                1 => SeekFrom::Current(offset as i64),
+                2 => SeekFrom::End(offset as i64),
+                _ => return -1,
+            };
+            file.eof = false;
+            match file.file.seek(pos) {
+                Ok(_) => 0,
+                Err(_) => -1,
+            }
+        })
+    }
+
+    unsafe extern "C" fn read(
+        file: *mut c_void,
+        buffer: *mut c_void,
+        size: usize,
+        count: usize,
+    ) -> usize {
+        _catch_unwind(0, || {
+            let file = &mut *(file as *mut PluginFile);
+            let len = size.saturating_mul(count);
+            if len == 0 {
+                return 0;
+            }
+            let buffer = std::slice::from_raw_parts_mut(buffer as *mut u8, len);
+            let mut filled = 0;
+            while filled < len {
+                match file.file.read(&mut buffer[filled..]) {
+                    Ok(0) => break,
+                    Ok(n) => filled += n,
+                    Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                    Err(_) => break,
+                }
+            }
+            if filled < len {
+                file.eof = true;
+            }
+            filled / size
+        })
+    }
+
+    unsafe extern "C" fn eof(file: *mut c_void) -> c_int {
+        let file = &*(file as *const PluginFile);
+        file.eof as c_int
+    }
+
+    unsafe extern "C" fn write(
+        file: *mut c_void,
+        buffer: *const c_void,
+        size: usize,
+        count: usize,
+    ) -> usize {
+        _catch_unwind(0, || {
+            let file = &mut *(file as *mut PluginFile);
+            let len = size.saturating_mul(count);
+            if len == 0 {
+                return 0;
+            }
+            let buffer = std::slice::from_raw_parts(buffer as *const u8, len);
+            match file.file.write_all(buffer) {
+                Ok(()) => count,
+                Err(_) => 0,
+            }
+        })
+    }
+
+    unsafe extern "C" fn flush(file: *mut c_void) -> c_int {
+        _catch_unwind(-1, || {
+            let file = &mut *(file as *mut PluginFile);
+            match file.file.flush() {
+                Ok(()) => 0,
+                Err(_) => -1,
+            }
+        })
+    }
+
+    unsafe extern "C" fn close(file: *mut c_void) -> c_int {
+        _catch_unwind(-1, || {
+            let mut file = Box::from_raw(file as *mut PluginFile);
+            match file.file.flush() {
+                Ok(()) => 0,
+                Err(_) => -1,
+            }
+        })
+    }
+
+    if !prefix.starts_with("/vsi") || !prefix.ends_with('/') {
+        return Err(GdalError::BadArgument(format!(
+            "file system prefix must start with '/vsi' and end with '/', got '{prefix}'"
+        )));
+    }
+    let c_prefix = CString::new(prefix)?;
+
+    let callbacks = unsafe { gdal_sys::VSIAllocFilesystemPluginCallbacksStruct() };
+    if callbacks.is_null() {
+        return Err(_last_null_pointer_err(
+            "VSIAllocFilesystemPluginCallbacksStruct",
+        ));
+    }
+    let user_data = Box::into_raw(Box::new(filesystem));
+    let rv = unsafe {
+        (*callbacks).pUserData = user_data as *mut c_void;
+        (*callbacks).stat = Some(stat::<F>);
+        (*callbacks).open = Some(open::<F>);
+        (*callbacks).tell = Some(tell);
+        (*callbacks).seek = Some(seek);
+        (*callbacks).read = Some(read);
+        (*callbacks).eof = Some(eof);
+        (*callbacks).write = Some(write);
+        (*callbacks).flush = Some(flush);
+        (*callbacks).close = Some(close);
+        // GDAL copies the callbacks
+        let rv = gdal_sys::VSIInstallPluginHandler(c_prefix.as_ptr(), callbacks);
+        gdal_sys::VSIFreeFilesystemPluginCallbacksStruct(callbacks);
+        rv
+    };
+    if rv != 0 {
+        drop(unsafe { Box::from_raw(user_data) });
+        return Err(_last_cpl_err(CPLErr::CE_Failure));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -318,4 +580,59 @@ mod tests {
         // Attempting to read without VSI prefix returns error.
         assert!(read_dir(zip_path, false).is_err());
     }
+
+    /// Serves the test fixtures from memory, read-only.
+    struct Fixtures;
+
+    impl VirtualFileSystem for Fixtures {
+        fn open(&self, path: &str, access: &str) -> std::io::Result<Box<dyn VirtualFile>> {
+            if !access.starts_with('r') || access.contains('+') {
+                return Err(ErrorKind::PermissionDenied.into());
+            }
+            let name = path.trim_start_matches("/vsitestfixtures/");
+            let data = std::fs::read(crate::test_utils::fixture(name))?;
+            Ok(Box::new(std::io::Cursor::new(data)))
+        }
+    }
+
+    #[test]
+    fn test_install_filesystem() {
+        let _nolog = crate::test_utils::SuppressGDALErrorLog::new();
+        install_filesystem("/vsitestfixtures/", Fixtures).unwrap();
+
+        let dataset = crate::Dataset::open("/vsitestfixtures/tinymarble.tif").unwrap();
+        assert_eq!(dataset.raster_size(), (100, 50));
+        assert_eq!(dataset.raster_count(), 3);
+        let band = dataset.rasterband(1).unwrap();
+        let expected = crate::Dataset::open(crate::test_utils::fixture("tinymarble.tif"))
+            .unwrap()
+            .rasterband(1)
+            .unwrap()
+            .read_band_as::<u8>()
+            .unwrap();
+        assert_eq!(band.read_band_as::<u8>().unwrap().data(), expected.data());
+
+        assert!(crate::Dataset::open("/vsitestfixtures/no_such_file.tif").is_err());
+
+        let c_path = CString::new("/vsitestfixtures/tinymarble.tif").unwrap();
+        let mut stat_buf = unsafe { std::mem::zeroed::<StatBuf>() };
+        let c_stat_buf = (&mut stat_buf as *mut StatBuf).cast();
+        assert_eq!(
+            unsafe { gdal_sys::VSIStatL(c_path.as_ptr(), c_stat_buf) },
+            0
+        );
+        let size = std::fs::metadata(crate::test_utils::fixture("tinymarble.tif"))
+            .unwrap()
+            .len();
+        assert_eq!(stat_buf.st_size as u64, size);
+
+        // the GeoPackage driver checks that the file exists with `VSIStatL`
+        let dataset = crate::Dataset::open("/vsitestfixtures/poly.gpkg").unwrap();
+        assert_eq!(dataset.layer_count(), 1);
+
+        assert!(matches!(
+            install_filesystem("vsinoslash", Fixtures),
+            Err(GdalError::BadArgument(_))
+        ));
+    }
 }